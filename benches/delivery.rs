@@ -0,0 +1,200 @@
+// Throughput of outgoing deliveries against local mock inbox servers.
+//
+//   cargo bench --bench delivery
+
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use actix_web::{App, HttpResponse, HttpServer, web};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use gekidan::domain::app_config::AppConfig;
+use gekidan::domain::delivery::delivery_job::{DeliveryJob, DeliveryJobStatus};
+use gekidan::domain::delivery::delivery_job_repository::DeliveryJobRepository;
+use gekidan::domain::delivery::delivery_service::DeliveryService;
+use gekidan::domain::error::{CommonError, CommonErrorCode};
+use gekidan::domain::instance::instance_health::{instance_host, InstanceHealth};
+use gekidan::domain::instance::instance_health_repository::InstanceHealthRepository;
+use gekidan::domain::instance::instance_service::InstanceService;
+use gekidan::domain::user::user::User;
+use gekidan::domain::user::user_repository::UserRepository;
+
+const JOBS: usize = 400;
+const HOSTS: usize = 4;
+// response time of the mock inbox
+const LATENCY: Duration = Duration::from_millis(20);
+
+struct MemoryDeliveryJobRepository {
+    jobs: Mutex<Vec<DeliveryJob>>,
+}
+
+#[async_trait]
+impl DeliveryJobRepository for MemoryDeliveryJobRepository {
+    async fn add(&self, new_job: &DeliveryJob) -> Result<(), CommonError> {
+        let mut jobs = self.jobs.lock().unwrap();
+        let mut job = new_job.clone();
+        job.id = jobs.len() as i32 + 1;
+        jobs.push(job);
+        Ok(())
+    }
+
+    async fn list_due(&self, now: DateTime<Utc>, limit: u64) -> Result<Vec<DeliveryJob>, CommonError> {
+        Ok(self.jobs.lock().unwrap().iter()
+            .filter(|j| j.status == DeliveryJobStatus::PENDING && j.next_attempt_at <= now)
+            .take(limit as usize)
+            .cloned()
+            .collect())
+    }
+
+    async fn update(&self, job: &DeliveryJob) -> Result<(), CommonError> {
+        let mut jobs = self.jobs.lock().unwrap();
+        match jobs.iter_mut().find(|j| j.id == job.id) {
+            Some(j) => {
+                *j = job.clone();
+                Ok(())
+            }
+            None => Err(CommonError::new(CommonErrorCode::UnexpectedError)),
+        }
+    }
+
+    async fn resume_host(&self, host: &str, now: DateTime<Utc>) -> Result<(), CommonError> {
+        for job in self.jobs.lock().unwrap().iter_mut() {
+            if job.status == DeliveryJobStatus::PENDING && instance_host(&job.inbox).as_deref() == Some(host) {
+                job.next_attempt_at = job.next_attempt_at.min(now);
+            }
+        }
+        Ok(())
+    }
+
+    async fn delete_finished_before(&self, cutoff: DateTime<Utc>) -> Result<(), CommonError> {
+        self.jobs.lock().unwrap().retain(|job| job.status == DeliveryJobStatus::PENDING || job.updated_at >= cutoff);
+        Ok(())
+    }
+}
+
+struct MemoryInstanceHealthRepository {
+    instances: Mutex<Vec<InstanceHealth>>,
+}
+
+#[async_trait]
+impl InstanceHealthRepository for MemoryInstanceHealthRepository {
+    async fn find(&self, host: &str) -> Result<Option<InstanceHealth>, CommonError> {
+        Ok(self.instances.lock().unwrap().iter().find(|i| i.host == host).cloned())
+    }
+
+    async fn list(&self) -> Result<Vec<InstanceHealth>, CommonError> {
+        Ok(self.instances.lock().unwrap().clone())
+    }
+
+    async fn save(&self, health: &InstanceHealth) -> Result<(), CommonError> {
+        let mut instances = self.instances.lock().unwrap();
+        match instances.iter_mut().find(|i| i.host == health.host) {
+            Some(i) => *i = health.clone(),
+            None => instances.push(health.clone()),
+        }
+        Ok(())
+    }
+}
+
+struct MockUserRepository {
+    user: User,
+}
+
+#[async_trait]
+impl UserRepository for MockUserRepository {
+    async fn add(&self, _new_user: &User) -> Result<(), CommonError> {
+        todo!()
+    }
+
+    async fn list(&self) -> Result<Vec<User>, CommonError> {
+        todo!()
+    }
+
+    async fn get(&self, _user_id: &str) -> Result<User, CommonError> {
+        Ok(self.user.clone())
+    }
+
+    async fn update(&self, _user: &User) -> Result<(), CommonError> {
+        todo!()
+    }
+
+    async fn delete(&self, _user_id: &str) -> Result<(), CommonError> {
+        todo!()
+    }
+
+    async fn find(&self, _username: &str) -> Result<Option<User>, CommonError> {
+        todo!()
+    }
+}
+
+async fn inbox() -> HttpResponse {
+    actix_web::rt::time::sleep(LATENCY).await;
+    HttpResponse::Accepted().finish()
+}
+
+async fn bench(user: &User, inboxes: &[String], concurrency: usize, host_concurrency: usize) {
+    let app_config = Arc::new(AppConfig {
+        environment: "bench".to_string(),
+        app_url: "http://bench.example.com/".to_string(),
+        app_url_host: "bench.example.com".to_string(),
+        admin_api_key: "".to_string(),
+        database_url: "".to_string(),
+        signature_clock_skew: 300,
+        processed_activity_retention: 604800,
+        delivery_max_attempts: 1,
+        delivery_retry_base: 60,
+        delivery_concurrency: concurrency,
+        delivery_host_concurrency: host_concurrency,
+        delivery_timeout: 10,
+        delivery_job_retention: 604800,
+        instance_unreachable_threshold: 604800,
+    });
+    let repository = Arc::new(MemoryDeliveryJobRepository { jobs: Mutex::new(vec![]) });
+    let service = DeliveryService::new(
+        app_config,
+        repository.clone(),
+        Arc::new(MockUserRepository { user: user.clone() }),
+        Arc::new(InstanceService::new(
+            604800,
+            Arc::new(MemoryInstanceHealthRepository { instances: Mutex::new(vec![]) }),
+            repository.clone(),
+        )),
+    );
+
+    let body = r#"{"type": "Create", "object": {"type": "Note", "content": "bench"}}"#;
+    for i in 0..JOBS {
+        service.enqueue(user, &inboxes[i % inboxes.len()], body).await.unwrap();
+    }
+
+    let client = service.client();
+    let started = Instant::now();
+    service.drain(&client).await.unwrap();
+    let elapsed = started.elapsed();
+
+    let delivered = repository.jobs.lock().unwrap().iter()
+        .filter(|j| j.status == DeliveryJobStatus::DELIVERED)
+        .count();
+    println!(
+        "concurrency={:>3} host_concurrency={:>3}: {}/{} delivered in {:>6} ms ({:.1} req/s)",
+        concurrency, host_concurrency, delivered, JOBS, elapsed.as_millis(),
+        delivered as f64 / elapsed.as_secs_f64()
+    );
+}
+
+#[actix_web::main]
+async fn main() {
+    // mock inbox servers, one per host
+    let mut inboxes = Vec::new();
+    for _ in 0..HOSTS {
+        let server = HttpServer::new(|| App::new().route("/inbox", web::post().to(inbox)))
+            .workers(1)
+            .bind(("127.0.0.1", 0))
+            .unwrap();
+        inboxes.push(format!("http://127.0.0.1:{}/inbox", server.addrs()[0].port()));
+        actix_web::rt::spawn(server.run());
+    }
+
+    let user = User::new("bench", "Bench One");
+    for (concurrency, host_concurrency) in [(1, 1), (4, 1), (16, 2), (16, 4), (64, 16)] {
+        bench(&user, &inboxes, concurrency, host_concurrency).await;
+    }
+}
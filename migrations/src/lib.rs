@@ -4,6 +4,23 @@ mod m20220713_000001_create_user_table;
 mod m20220724_000001_create_user_rsa_key_table;
 mod m20230801_000001_create_note_table;
 mod m20230808_000001_create_follower_table;
+mod m20230901_000001_create_remote_actor_table;
+mod m20230905_000001_create_remote_note_table;
+mod m20230910_000001_create_following_table;
+mod m20230915_000001_create_processed_activity_table;
+mod m20230915_000002_add_follower_user_id_actor_index;
+mod m20230920_000001_create_delivery_job_table;
+mod m20230925_000001_create_instance_health_table;
+mod m20230930_000001_create_note_history_table;
+mod m20231005_000001_create_user_tombstone_table;
+mod m20231010_000001_add_note_visibility;
+mod m20231015_000001_add_user_hide_collections;
+mod m20231020_000001_add_note_pinned_at;
+mod m20231025_000001_add_user_profile;
+mod m20231030_000001_add_user_locked;
+mod m20231030_000002_create_follow_request_table;
+mod m20231105_000001_create_block_table;
+mod m20231110_000001_create_note_recipient_table;
 
 pub struct Migrator;
 
@@ -15,6 +32,23 @@ impl MigratorTrait for Migrator {
             Box::new(m20220724_000001_create_user_rsa_key_table::Migration),
             Box::new(m20230801_000001_create_note_table::Migration),
             Box::new(m20230808_000001_create_follower_table::Migration),
+            Box::new(m20230901_000001_create_remote_actor_table::Migration),
+            Box::new(m20230905_000001_create_remote_note_table::Migration),
+            Box::new(m20230910_000001_create_following_table::Migration),
+            Box::new(m20230915_000001_create_processed_activity_table::Migration),
+            Box::new(m20230915_000002_add_follower_user_id_actor_index::Migration),
+            Box::new(m20230920_000001_create_delivery_job_table::Migration),
+            Box::new(m20230925_000001_create_instance_health_table::Migration),
+            Box::new(m20230930_000001_create_note_history_table::Migration),
+            Box::new(m20231005_000001_create_user_tombstone_table::Migration),
+            Box::new(m20231010_000001_add_note_visibility::Migration),
+            Box::new(m20231015_000001_add_user_hide_collections::Migration),
+            Box::new(m20231020_000001_add_note_pinned_at::Migration),
+            Box::new(m20231025_000001_add_user_profile::Migration),
+            Box::new(m20231030_000001_add_user_locked::Migration),
+            Box::new(m20231030_000002_create_follow_request_table::Migration),
+            Box::new(m20231105_000001_create_block_table::Migration),
+            Box::new(m20231110_000001_create_note_recipient_table::Migration),
         ]
     }
 }
@@ -0,0 +1,58 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RemoteActor::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RemoteActor::Id)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(RemoteActor::PreferredUsername).string().not_null())
+                    .col(ColumnDef::new(RemoteActor::Inbox).string().not_null())
+                    .col(ColumnDef::new(RemoteActor::SharedInbox).string())
+                    .col(ColumnDef::new(RemoteActor::PublicKeyId).string().not_null())
+                    .col(ColumnDef::new(RemoteActor::PublicKeyPem).string().not_null())
+                    .col(ColumnDef::new(RemoteActor::FetchedAt).timestamp_with_time_zone().not_null())
+                    .to_owned(),
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-remote-actor-public_key_id")
+                    .table(RemoteActor::Table)
+                    .col(RemoteActor::PublicKeyId)
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RemoteActor::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum RemoteActor {
+    Table,
+    Id,
+    PreferredUsername,
+    Inbox,
+    SharedInbox,
+    PublicKeyId,
+    PublicKeyPem,
+    FetchedAt,
+}
@@ -0,0 +1,77 @@
+use sea_orm_migration::prelude::*;
+use crate::m20230901_000001_create_remote_actor_table::RemoteActor;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(RemoteNote::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(RemoteNote::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key()
+                            .auto_increment()
+                    )
+                    .col(ColumnDef::new(RemoteNote::UserId).string().not_null())
+                    .col(ColumnDef::new(RemoteNote::ObjectId).string().not_null())
+                    .col(ColumnDef::new(RemoteNote::Actor).string().not_null())
+                    .col(ColumnDef::new(RemoteNote::Content).string().not_null())
+                    .col(ColumnDef::new(RemoteNote::Summary).string())
+                    .col(ColumnDef::new(RemoteNote::InReplyTo).string())
+                    .col(ColumnDef::new(RemoteNote::Published).timestamp_with_time_zone().not_null())
+                    .col(
+                        ColumnDef::new(RemoteNote::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-remote-note-actor")
+                            .from(RemoteNote::Table, RemoteNote::Actor)
+                            .to(RemoteActor::Table, RemoteActor::Id)
+                    )
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-remote-note-user_id-object_id")
+                    .table(RemoteNote::Table)
+                    .col(RemoteNote::UserId)
+                    .col(RemoteNote::ObjectId)
+                    .unique()
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(RemoteNote::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum RemoteNote {
+    Table,
+    Id,
+    UserId,
+    ObjectId,
+    Actor,
+    Content,
+    Summary,
+    InReplyTo,
+    Published,
+    CreatedAt,
+}
@@ -0,0 +1,66 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Following::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Following::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key()
+                            .auto_increment()
+                    )
+                    .col(ColumnDef::new(Following::UserId).string().not_null())
+                    .col(ColumnDef::new(Following::Actor).string().not_null())
+                    .col(ColumnDef::new(Following::Object).string().not_null())
+                    .col(ColumnDef::new(Following::Status).integer().not_null())
+                    .col(
+                        ColumnDef::new(Following::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(Following::UpdatedAt).timestamp_with_time_zone().not_null())
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-following-user_id-actor")
+                    .table(Following::Table)
+                    .col(Following::UserId)
+                    .col(Following::Actor)
+                    .unique()
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Following::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum Following {
+    Table,
+    Id,
+    UserId,
+    Actor,
+    Object,
+    Status,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,70 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(ProcessedActivity::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(ProcessedActivity::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key()
+                            .auto_increment()
+                    )
+                    .col(ColumnDef::new(ProcessedActivity::ActivityId).string().not_null())
+                    .col(ColumnDef::new(ProcessedActivity::UserId).string().not_null())
+                    .col(
+                        ColumnDef::new(ProcessedActivity::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-processed-activity-activity_id-user_id")
+                    .table(ProcessedActivity::Table)
+                    .col(ProcessedActivity::ActivityId)
+                    .col(ProcessedActivity::UserId)
+                    .unique()
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-processed-activity-created_at")
+                    .table(ProcessedActivity::Table)
+                    .col(ProcessedActivity::CreatedAt)
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(ProcessedActivity::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum ProcessedActivity {
+    Table,
+    Id,
+    ActivityId,
+    UserId,
+    CreatedAt,
+}
@@ -0,0 +1,41 @@
+use sea_orm_migration::prelude::*;
+use crate::m20230808_000001_create_follower_table::Follower;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // keep the oldest row of duplicated followers
+        manager
+            .get_connection()
+            .execute_unprepared(
+                "DELETE FROM follower WHERE id NOT IN (SELECT MIN(id) FROM follower GROUP BY user_id, actor)"
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-follower-user_id-actor")
+                    .table(Follower::Table)
+                    .col(Follower::UserId)
+                    .col(Follower::Actor)
+                    .unique()
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_index(
+                Index::drop()
+                    .name("idx-follower-user_id-actor")
+                    .table(Follower::Table)
+                    .to_owned()
+            )
+            .await
+    }
+}
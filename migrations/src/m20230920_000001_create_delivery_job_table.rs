@@ -0,0 +1,73 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(DeliveryJob::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(DeliveryJob::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key()
+                            .auto_increment()
+                    )
+                    .col(ColumnDef::new(DeliveryJob::UserId).string().not_null())
+                    .col(ColumnDef::new(DeliveryJob::Inbox).string().not_null())
+                    .col(ColumnDef::new(DeliveryJob::Body).text().not_null())
+                    .col(ColumnDef::new(DeliveryJob::Status).integer().not_null())
+                    .col(ColumnDef::new(DeliveryJob::Attempts).integer().not_null())
+                    .col(ColumnDef::new(DeliveryJob::NextAttemptAt).timestamp_with_time_zone().not_null())
+                    .col(ColumnDef::new(DeliveryJob::LastStatus).integer())
+                    .col(ColumnDef::new(DeliveryJob::LastError).string())
+                    .col(
+                        ColumnDef::new(DeliveryJob::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .col(ColumnDef::new(DeliveryJob::UpdatedAt).timestamp_with_time_zone().not_null())
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-delivery-job-status-next_attempt_at")
+                    .table(DeliveryJob::Table)
+                    .col(DeliveryJob::Status)
+                    .col(DeliveryJob::NextAttemptAt)
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(DeliveryJob::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum DeliveryJob {
+    Table,
+    Id,
+    UserId,
+    Inbox,
+    Body,
+    Status,
+    Attempts,
+    NextAttemptAt,
+    LastStatus,
+    LastError,
+    CreatedAt,
+    UpdatedAt,
+}
@@ -0,0 +1,46 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(InstanceHealth::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(InstanceHealth::Host)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(InstanceHealth::ConsecutiveFailures).integer().not_null())
+                    .col(ColumnDef::new(InstanceHealth::LastSuccessAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(InstanceHealth::LastFailureAt).timestamp_with_time_zone())
+                    .col(ColumnDef::new(InstanceHealth::UnreachableSince).timestamp_with_time_zone())
+                    .col(ColumnDef::new(InstanceHealth::UpdatedAt).timestamp_with_time_zone().not_null())
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(InstanceHealth::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum InstanceHealth {
+    Table,
+    Host,
+    ConsecutiveFailures,
+    LastSuccessAt,
+    LastFailureAt,
+    UnreachableSince,
+    UpdatedAt,
+}
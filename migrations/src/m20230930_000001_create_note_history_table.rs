@@ -0,0 +1,66 @@
+use sea_orm_migration::prelude::*;
+use crate::m20230801_000001_create_note_table::Note;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(NoteHistory::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NoteHistory::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key()
+                            .auto_increment()
+                    )
+                    .col(ColumnDef::new(NoteHistory::NoteId).string().not_null())
+                    .col(ColumnDef::new(NoteHistory::Content).string().not_null())
+                    .col(
+                        ColumnDef::new(NoteHistory::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-note-history-note")
+                            .from(NoteHistory::Table, NoteHistory::NoteId)
+                            .to(Note::Table, Note::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-note-history-note_id")
+                    .table(NoteHistory::Table)
+                    .col(NoteHistory::NoteId)
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(NoteHistory::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum NoteHistory {
+    Table,
+    Id,
+    NoteId,
+    Content,
+    CreatedAt,
+}
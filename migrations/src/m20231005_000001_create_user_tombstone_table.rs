@@ -0,0 +1,56 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(UserTombstone::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(UserTombstone::UserId)
+                            .string()
+                            .not_null()
+                            .primary_key(),
+                    )
+                    .col(ColumnDef::new(UserTombstone::Username).string().not_null())
+                    .col(
+                        ColumnDef::new(UserTombstone::DeletedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-user-tombstone-username")
+                    .table(UserTombstone::Table)
+                    .col(UserTombstone::Username)
+                    .unique()
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(UserTombstone::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+pub enum UserTombstone {
+    Table,
+    UserId,
+    Username,
+    DeletedAt,
+}
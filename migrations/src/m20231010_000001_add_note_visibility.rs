@@ -0,0 +1,36 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // existing notes were delivered as public
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Note::Table)
+                    .add_column(ColumnDef::new(Note::Visibility).integer().not_null().default(1))
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Note::Table)
+                    .drop_column(Note::Visibility)
+                    .to_owned()
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Note {
+    Table,
+    Visibility,
+}
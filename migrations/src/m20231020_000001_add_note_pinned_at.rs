@@ -0,0 +1,35 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Note::Table)
+                    .add_column(ColumnDef::new(Note::PinnedAt).string().null())
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .alter_table(
+                Table::alter()
+                    .table(Note::Table)
+                    .drop_column(Note::PinnedAt)
+                    .to_owned()
+            )
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Note {
+    Table,
+    PinnedAt,
+}
@@ -0,0 +1,52 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        // sqlite alters a single column at a time
+        let columns = [
+            ColumnDef::new(User::Summary).string().not_null().default("").to_owned(),
+            ColumnDef::new(User::AvatarUrl).string().null().to_owned(),
+            ColumnDef::new(User::HeaderUrl).string().null().to_owned(),
+            // json array of name/value pairs
+            ColumnDef::new(User::Fields).string().not_null().default("[]").to_owned(),
+        ];
+        for mut column in columns {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(User::Table)
+                        .add_column(&mut column)
+                        .to_owned()
+                )
+                .await?;
+        }
+        Ok(())
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        for column in [User::Summary, User::AvatarUrl, User::HeaderUrl, User::Fields] {
+            manager
+                .alter_table(
+                    Table::alter()
+                        .table(User::Table)
+                        .drop_column(column)
+                        .to_owned()
+                )
+                .await?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Iden)]
+enum User {
+    Table,
+    Summary,
+    AvatarUrl,
+    HeaderUrl,
+    Fields,
+}
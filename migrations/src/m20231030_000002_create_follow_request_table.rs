@@ -0,0 +1,65 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(FollowRequest::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(FollowRequest::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key()
+                            .auto_increment()
+                    )
+                    .col(ColumnDef::new(FollowRequest::UserId).string().not_null())
+                    .col(ColumnDef::new(FollowRequest::Actor).string().not_null())
+                    .col(ColumnDef::new(FollowRequest::Object).string().not_null())
+                    .col(ColumnDef::new(FollowRequest::Inbox).string().not_null())
+                    .col(
+                        ColumnDef::new(FollowRequest::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .to_owned()
+            )
+            .await?;
+
+        // one pending request per remote actor
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-follow-request-user-id-actor")
+                    .table(FollowRequest::Table)
+                    .col(FollowRequest::UserId)
+                    .col(FollowRequest::Actor)
+                    .unique()
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(FollowRequest::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum FollowRequest {
+    Table,
+    Id,
+    UserId,
+    Actor,
+    Object,
+    Inbox,
+    CreatedAt,
+}
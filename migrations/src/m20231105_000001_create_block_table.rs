@@ -0,0 +1,62 @@
+use sea_orm_migration::prelude::*;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(Block::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(Block::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key()
+                            .auto_increment()
+                    )
+                    .col(ColumnDef::new(Block::UserId).string().not_null())
+                    .col(ColumnDef::new(Block::Actor).string().not_null())
+                    .col(ColumnDef::new(Block::Object).string().not_null())
+                    .col(
+                        ColumnDef::new(Block::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .to_owned()
+            )
+            .await?;
+
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-block-user-id-actor")
+                    .table(Block::Table)
+                    .col(Block::UserId)
+                    .col(Block::Actor)
+                    .unique()
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(Block::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum Block {
+    Table,
+    Id,
+    UserId,
+    Actor,
+    Object,
+    CreatedAt,
+}
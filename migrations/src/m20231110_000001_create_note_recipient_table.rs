@@ -0,0 +1,69 @@
+use sea_orm_migration::prelude::*;
+use crate::m20230801_000001_create_note_table::Note;
+
+#[derive(DeriveMigrationName)]
+pub struct Migration;
+
+#[async_trait::async_trait]
+impl MigrationTrait for Migration {
+    async fn up(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .create_table(
+                Table::create()
+                    .table(NoteRecipient::Table)
+                    .if_not_exists()
+                    .col(
+                        ColumnDef::new(NoteRecipient::Id)
+                            .integer()
+                            .not_null()
+                            .primary_key()
+                            .auto_increment()
+                    )
+                    .col(ColumnDef::new(NoteRecipient::NoteId).string().not_null())
+                    .col(ColumnDef::new(NoteRecipient::Inbox).string().not_null())
+                    .col(
+                        ColumnDef::new(NoteRecipient::CreatedAt)
+                            .timestamp_with_time_zone()
+                            .default(Expr::current_timestamp())
+                            .not_null()
+                    )
+                    .foreign_key(
+                        ForeignKey::create()
+                            .name("fk-note-recipient-note")
+                            .from(NoteRecipient::Table, NoteRecipient::NoteId)
+                            .to(Note::Table, Note::Id)
+                            .on_delete(ForeignKeyAction::Cascade)
+                    )
+                    .to_owned()
+            )
+            .await?;
+
+        // each inbox is recorded once per note
+        manager
+            .create_index(
+                Index::create()
+                    .name("idx-note-recipient-note-id-inbox")
+                    .table(NoteRecipient::Table)
+                    .col(NoteRecipient::NoteId)
+                    .col(NoteRecipient::Inbox)
+                    .unique()
+                    .to_owned()
+            )
+            .await
+    }
+
+    async fn down(&self, manager: &SchemaManager) -> Result<(), DbErr> {
+        manager
+            .drop_table(Table::drop().table(NoteRecipient::Table).to_owned())
+            .await
+    }
+}
+
+#[derive(Iden)]
+enum NoteRecipient {
+    Table,
+    Id,
+    NoteId,
+    Inbox,
+    CreatedAt,
+}
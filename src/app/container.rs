@@ -2,23 +2,61 @@ use std::sync::Arc;
 use sea_orm::Database;
 use crate::domain::activity_pub::activity_pub_service::ActivityPubService;
 use crate::domain::app_config::AppConfig;
+use crate::domain::block::block_repository::BlockRepository;
+use crate::domain::delivery::delivery_job_repository::DeliveryJobRepository;
+use crate::domain::delivery::delivery_service::DeliveryService;
+use crate::domain::follow_request::follow_request_repository::FollowRequestRepository;
 use crate::domain::follower::follower_repository::FollowerRepository;
+use crate::domain::following::following_repository::FollowingRepository;
+use crate::domain::instance::instance_health_repository::InstanceHealthRepository;
+use crate::domain::instance::instance_service::InstanceService;
+use crate::domain::note::note_history_repository::NoteHistoryRepository;
+use crate::domain::note::note_recipient_repository::NoteRecipientRepository;
 use crate::domain::note::note_repository::NoteRepository;
+use crate::domain::processed_activity::processed_activity_repository::ProcessedActivityRepository;
+use crate::domain::remote_actor::remote_actor_repository::RemoteActorRepository;
+use crate::domain::remote_actor::remote_actor_service::RemoteActorService;
+use crate::domain::remote_note::remote_note_repository::RemoteNoteRepository;
 use crate::domain::user::user_repository::UserRepository;
 use crate::domain::user::user_service::UserService;
+use crate::domain::user::user_tombstone_repository::UserTombstoneRepository;
 use crate::infrastructure::config::env_file::load_app_config;
+use crate::infrastructure::repositories::block::BlockSeaORMRepository;
+use crate::infrastructure::repositories::delivery_job::DeliveryJobSeaORMRepository;
+use crate::infrastructure::repositories::follow_request::FollowRequestSeaORMRepository;
 use crate::infrastructure::repositories::follower::FollowerSeaORMRepository;
+use crate::infrastructure::repositories::following::FollowingSeaORMRepository;
+use crate::infrastructure::repositories::instance_health::InstanceHealthSeaORMRepository;
 use crate::infrastructure::repositories::note::NoteSeaORMRepository;
+use crate::infrastructure::repositories::note_history::NoteHistorySeaORMRepository;
+use crate::infrastructure::repositories::note_recipient::NoteRecipientSeaORMRepository;
+use crate::infrastructure::repositories::processed_activity::ProcessedActivitySeaORMRepository;
+use crate::infrastructure::repositories::remote_actor::RemoteActorSeaORMRepository;
+use crate::infrastructure::repositories::remote_note::RemoteNoteSeaORMRepository;
 use crate::infrastructure::repositories::user::UserSeaORMRepository;
+use crate::infrastructure::repositories::user_tombstone::UserTombstoneSeaORMRepository;
 use crate::usecase::activity_pub::ActivityPubUseCase;
+use crate::usecase::instance_management::InstanceManagementUseCase;
+use crate::usecase::user_block::UserBlockUseCase;
+use crate::usecase::user_follow_request::UserFollowRequestUseCase;
+use crate::usecase::user_follower::UserFollowerUseCase;
+use crate::usecase::user_following::UserFollowingUseCase;
 use crate::usecase::user_management::UserManagementUseCase;
 use crate::usecase::user_note::UserNoteUseCase;
+use crate::usecase::user_received_note::UserReceivedNoteUseCase;
 
 pub struct Container {
     pub app_config: Arc<AppConfig>,
+    pub delivery_service: Arc<DeliveryService>,
     pub activity_pub_usecase: Arc<ActivityPubUseCase>,
+    pub instance_management_usecase: Arc<InstanceManagementUseCase>,
+    pub user_block_usecase: Arc<UserBlockUseCase>,
+    pub user_follow_request_usecase: Arc<UserFollowRequestUseCase>,
+    pub user_follower_usecase: Arc<UserFollowerUseCase>,
+    pub user_following_usecase: Arc<UserFollowingUseCase>,
     pub user_management_usecase: Arc<UserManagementUseCase>,
     pub user_note_usecase: Arc<UserNoteUseCase>,
+    pub user_received_note_usecase: Arc<UserReceivedNoteUseCase>,
 }
 
 impl Container {
@@ -31,15 +69,74 @@ impl Container {
         let user_repository: Arc<dyn UserRepository> = Arc::new(
             UserSeaORMRepository::new(db_conn.clone())
         );
+        let user_tombstone_repository: Arc<dyn UserTombstoneRepository> = Arc::new(
+            UserTombstoneSeaORMRepository::new(db_conn.clone())
+        );
         let note_repository: Arc<dyn NoteRepository> = Arc::new(
             NoteSeaORMRepository::new(db_conn.clone())
         );
+        let note_history_repository: Arc<dyn NoteHistoryRepository> = Arc::new(
+            NoteHistorySeaORMRepository::new(db_conn.clone())
+        );
+        let note_recipient_repository: Arc<dyn NoteRecipientRepository> = Arc::new(
+            NoteRecipientSeaORMRepository::new(db_conn.clone())
+        );
         let follower_repository: Arc<dyn FollowerRepository> = Arc::new(
             FollowerSeaORMRepository::new(db_conn.clone())
         );
+        let following_repository: Arc<dyn FollowingRepository> = Arc::new(
+            FollowingSeaORMRepository::new(db_conn.clone())
+        );
+        let follow_request_repository: Arc<dyn FollowRequestRepository> = Arc::new(
+            FollowRequestSeaORMRepository::new(db_conn.clone())
+        );
+        let block_repository: Arc<dyn BlockRepository> = Arc::new(
+            BlockSeaORMRepository::new(db_conn.clone())
+        );
+        let remote_actor_repository: Arc<dyn RemoteActorRepository> = Arc::new(
+            RemoteActorSeaORMRepository::new(db_conn.clone())
+        );
+        let remote_note_repository: Arc<dyn RemoteNoteRepository> = Arc::new(
+            RemoteNoteSeaORMRepository::new(db_conn.clone())
+        );
 
+        let processed_activity_repository: Arc<dyn ProcessedActivityRepository> = Arc::new(
+            ProcessedActivitySeaORMRepository::new(db_conn.clone())
+        );
+
+        let delivery_job_repository: Arc<dyn DeliveryJobRepository> = Arc::new(
+            DeliveryJobSeaORMRepository::new(db_conn.clone())
+        );
+
+        let instance_health_repository: Arc<dyn InstanceHealthRepository> = Arc::new(
+            InstanceHealthSeaORMRepository::new(db_conn.clone())
+        );
+
+        let instance_service = Arc::new(
+            InstanceService::new(
+                app_config.instance_unreachable_threshold,
+                instance_health_repository,
+                delivery_job_repository.clone(),
+            )
+        );
+        let delivery_service = Arc::new(
+            DeliveryService::new(
+                app_config.clone(),
+                delivery_job_repository,
+                user_repository.clone(),
+                instance_service.clone(),
+            )
+        );
+        let remote_actor_service = Arc::new(
+            RemoteActorService::new(remote_actor_repository.clone())
+        );
         let activity_pub_service = Arc::new(
-            ActivityPubService::new(user_repository.clone()),
+            ActivityPubService::new(
+                user_repository.clone(),
+                user_tombstone_repository.clone(),
+                remote_actor_service.clone(),
+                delivery_service.clone(),
+            ),
         );
         let activity_pub_usecase = Arc::new(
             ActivityPubUseCase::new(
@@ -47,16 +144,74 @@ impl Container {
                 activity_pub_service.clone(),
                 user_repository.clone(),
                 follower_repository.clone(),
+                following_repository.clone(),
+                follow_request_repository.clone(),
+                block_repository.clone(),
+                note_repository.clone(),
+                remote_note_repository.clone(),
+                processed_activity_repository,
+                instance_service.clone(),
             ),
         );
 
+        let instance_management_usecase = Arc::new(
+            InstanceManagementUseCase::new(instance_service)
+        );
+
+        let user_block_usecase = Arc::new(
+            UserBlockUseCase::new(
+                app_config.clone(),
+                block_repository,
+                follower_repository.clone(),
+                following_repository.clone(),
+                follow_request_repository.clone(),
+                user_repository.clone(),
+                remote_actor_service.clone(),
+                activity_pub_service.clone(),
+            )
+        );
+
+        let user_follow_request_usecase = Arc::new(
+            UserFollowRequestUseCase::new(
+                app_config.clone(),
+                follow_request_repository,
+                follower_repository.clone(),
+                user_repository.clone(),
+                activity_pub_service.clone(),
+            )
+        );
+
+        let user_follower_usecase = Arc::new(
+            UserFollowerUseCase::new(
+                app_config.clone(),
+                follower_repository.clone(),
+                user_repository.clone(),
+                activity_pub_service.clone(),
+            )
+        );
+
+        let user_following_usecase = Arc::new(
+            UserFollowingUseCase::new(
+                app_config.clone(),
+                following_repository.clone(),
+                user_repository.clone(),
+                remote_actor_service.clone(),
+                activity_pub_service.clone(),
+            )
+        );
+
         let user_service: Arc<UserService> = Arc::new(
-            UserService::new(user_repository.clone())
+            UserService::new(user_repository.clone(), user_tombstone_repository.clone())
         );
         let user_management_usecase = Arc::new(
             UserManagementUseCase::new(
+                app_config.clone(),
                 user_repository.clone(),
+                user_tombstone_repository,
+                follower_repository.clone(),
+                following_repository.clone(),
                 user_service.clone(),
+                activity_pub_service.clone(),
             )
         );
 
@@ -64,17 +219,30 @@ impl Container {
             UserNoteUseCase::new(
                 app_config.clone(),
                 note_repository,
-                user_repository,
+                note_history_repository,
+                note_recipient_repository,
+                user_repository.clone(),
                 follower_repository,
                 activity_pub_service,
             )
         );
 
+        let user_received_note_usecase = Arc::new(
+            UserReceivedNoteUseCase::new(remote_note_repository, user_repository)
+        );
+
         Container {
             app_config,
+            delivery_service,
             activity_pub_usecase,
+            instance_management_usecase,
+            user_block_usecase,
+            user_follow_request_usecase,
+            user_follower_usecase,
+            user_following_usecase,
             user_management_usecase,
             user_note_usecase,
+            user_received_note_usecase,
         }
     }
 }
@@ -39,6 +39,10 @@ pub fn create_app() -> App<
         .service(
             web::scope("/nodeinfo/2.1").route("", web::get().to(activity_pub::node_info))
         )
+        .service(
+            // shared inbox
+            web::scope("/inbox").route("", web::post().to(activity_pub::post_shared_inbox))
+        )
         .service(
             // require x-admin-api-key header
             web::scope("/admin")
@@ -49,6 +53,11 @@ pub fn create_app() -> App<
                         .route("/{user_id}", web::get().to(user_management::get_user))
                         .route("/{user_id}", web::put().to(user_management::update_user))
                         .route("/{user_id}", web::delete().to(user_management::delete_user))
+                        .route("/{user_id}/rotate_key", web::post().to(user_management::rotate_user_key))
+                )
+                .service(
+                    web::scope("/instances")
+                        .route("", web::get().to(instance_management::list_instances))
                 )
         )
         .service(
@@ -60,14 +69,55 @@ pub fn create_app() -> App<
                         .route("", web::post().to(user_note::create_user_note))
                         .route("", web::get().to(user_note::list_user_notes))
                         .route("/{note_id}", web::get().to(user_note::get_user_note))
+                        .route("/{note_id}", web::put().to(user_note::update_user_note))
                         .route("/{note_id}", web::delete().to(user_note::delete_user_note))
+                        .route("/{note_id}/history", web::get().to(user_note::list_user_note_history))
+                        .route("/{note_id}/pin", web::post().to(user_note::pin_user_note))
+                        .route("/{note_id}/unpin", web::post().to(user_note::unpin_user_note))
+                )
+                .service(
+                    web::scope("/following")
+                        .route("", web::post().to(user_following::create_user_following))
+                        // public collection
+                        .route("", web::get().to(activity_pub::get_following))
+                        .route("/list", web::get().to(user_following::list_user_followings))
+                        .route("/{following_id}", web::get().to(user_following::get_user_following))
+                        .route("/{following_id}", web::delete().to(user_following::delete_user_following))
+                )
+                .service(
+                    web::scope("/followers")
+                        // public collection
+                        .route("", web::get().to(activity_pub::get_followers))
+                        .route("/remove", web::post().to(user_follower::remove_user_follower))
+                )
+                .service(
+                    web::scope("/follow_requests")
+                        .route("", web::get().to(user_follow_request::list_user_follow_requests))
+                        .route("/{request_id}/accept", web::post().to(user_follow_request::accept_user_follow_request))
+                        .route("/{request_id}/reject", web::post().to(user_follow_request::reject_user_follow_request))
+                )
+                .service(
+                    web::scope("/blocks")
+                        .route("", web::post().to(user_block::create_user_block))
+                        .route("", web::get().to(user_block::list_user_blocks))
+                        .route("/{block_id}", web::delete().to(user_block::delete_user_block))
+                )
+                .service(
+                    web::scope("/received_notes")
+                        .route("", web::get().to(user_received_note::list_received_notes))
                 )
 
                 // public
                 .route("", web::get().to(activity_pub::actor_by_user_id))
                 .route("/inbox", web::get().to(echo::echo_ok))
                 .route("/inbox", web::post().to(activity_pub::post_inbox))
-                .route("/outbox", web::get().to(activity_pub::get_outbox)),
+                .route("/outbox", web::get().to(activity_pub::get_outbox))
+                .route("/collections/featured", web::get().to(activity_pub::get_featured)),
+        )
+        .service(
+            web::scope("/notes/{note_id}")
+                .route("", web::get().to(activity_pub::get_note))
+                .route("/activity", web::get().to(activity_pub::get_note_activity))
         )
         .service(
             web::scope("/@{username}")
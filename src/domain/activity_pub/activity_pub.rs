@@ -1,9 +1,5 @@
-use std::fmt::Formatter;
-use std::marker::PhantomData;
-use std::str::FromStr;
-use serde::{de, Deserialize, Deserializer, Serialize};
-use serde::de::{Error, MapAccess, Visitor};
-use void::Void;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
 
 #[derive(Serialize)]
 pub struct WebFinger {
@@ -18,6 +14,33 @@ pub struct WebFingerLinkItem {
     pub href: String,
 }
 
+#[derive(Debug, Deserialize)]
+pub struct RemoteWebFinger {
+    pub subject: String,
+    #[serde(default)]
+    pub links: Vec<RemoteWebFingerLinkItem>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RemoteWebFingerLinkItem {
+    pub rel: String,
+    pub r#type: Option<String>,
+    pub href: Option<String>,
+}
+
+impl RemoteWebFinger {
+    // url of the actor document
+    pub fn self_link(&self) -> Option<&String> {
+        self.links.iter()
+            .filter(|l| l.rel == "self")
+            .filter(|l| match &l.r#type {
+                Some(t) => t == "application/activity+json" || t.starts_with("application/ld+json"),
+                None => false,
+            })
+            .find_map(|l| l.href.as_ref())
+    }
+}
+
 #[derive(Serialize)]
 pub struct NodeInfoLinks {
     pub links: Vec<NodeIngoLinkItem>,
@@ -66,6 +89,26 @@ pub struct NodeInfoUsers {
 #[derive(Serialize)]
 pub struct NodeInfoMetadata {}
 
+#[derive(Serialize)]
+pub struct PersonImage {
+    pub r#type: String,
+    pub url: String,
+}
+
+// profile field
+#[derive(Serialize)]
+pub struct PropertyValue {
+    pub r#type: String,
+    pub name: String,
+    pub value: String,
+}
+
+#[derive(Serialize)]
+pub struct PersonEndpoints {
+    #[serde(rename(serialize = "sharedInbox"))]
+    pub shared_inbox: String,
+}
+
 #[derive(Serialize)]
 pub struct PersonPublicKey {
     pub id: String,
@@ -77,13 +120,25 @@ pub struct PersonPublicKey {
 #[derive(Serialize)]
 pub struct Person {
     #[serde(rename(serialize = "@context"))]
-    pub context: Vec<String>,
+    pub context: Vec<Value>,
     pub id: String,
     pub r#type: String,
     #[serde(rename(serialize = "preferredUsername"))]
     pub preferred_username: String,
+    pub name: String,
+    pub summary: String,
+    pub url: String,
+    pub published: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub icon: Option<PersonImage>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub image: Option<PersonImage>,
+    pub attachment: Vec<PropertyValue>,
+    pub endpoints: PersonEndpoints,
     pub inbox: String,
     pub outbox: String,
+    pub followers: String,
+    pub following: String,
     #[serde(rename(serialize = "sharedInbox"))]
     pub shared_inbox: String,
     #[serde(rename(serialize = "publicKey"))]
@@ -100,10 +155,15 @@ pub struct ActivityObject {
     pub context: String,
     pub r#type: String,
     pub id: String,
+    #[serde(rename(serialize = "attributedTo"))]
+    pub attributed_to: String,
+    pub url: String,
     pub published: String,
     pub to: Vec<String>,
     pub cc: Vec<String>,
     pub content: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated: Option<String>,
 }
 
 #[derive(Serialize)]
@@ -125,6 +185,9 @@ pub struct ActivityItemParams {
     pub note_id: String,
     pub content: String,
     pub published: String,
+    pub updated: Option<String>,
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
 }
 
 impl ActivityNoteItem {
@@ -132,99 +195,173 @@ impl ActivityNoteItem {
         ActivityNoteItem {
             context: "https://www.w3.org/ns/activitystreams".to_string(),
             r#type: "Create".to_string(),
-            id: format!("{}notes/{}", params.app_url, params.note_id),
+            // https://foo.example.com/notes/{note_id}/activity
+            id: format!("{}notes/{}/activity", params.app_url, params.note_id),
             published: params.published.clone(),
-            to: vec!["https://www.w3.org/ns/activitystreams#Public".to_string()],
-            cc: vec![format!("{}users/{}/followers", params.app_url, params.user_id)],
+            to: params.to.clone(),
+            cc: params.cc.clone(),
             actor: format!("{}users/{}", params.app_url, params.user_id),
             object: ActivityObject {
                 context: "https://www.w3.org/ns/activitystreams".to_string(),
                 r#type: "Note".to_string(),
+                // https://foo.example.com/notes/{note_id}
                 id: format!("{}notes/{}", params.app_url, params.note_id),
+                attributed_to: format!("{}users/{}", params.app_url, params.user_id),
+                // no html page yet, the object itself
+                url: format!("{}notes/{}", params.app_url, params.note_id),
                 published: params.published.clone(),
-                to: vec!["https://www.w3.org/ns/activitystreams#Public".to_string()],
-                cc: vec![format!("{}users/{}/followers", params.app_url, params.user_id)],
+                to: params.to.clone(),
+                cc: params.cc.clone(),
                 content: params.content.clone(),
+                updated: params.updated.clone(),
             },
         }
     }
 }
 
 #[derive(Serialize)]
-pub struct ActivityNoteBox {
+pub struct UpdateNoteActivity {
     #[serde(rename(serialize = "@context"))]
     pub context: String,
-    pub summary: String,
     pub r#type: String,
-    #[serde(rename(serialize = "totalItems"))]
-    pub total_items: i16,
-    #[serde(rename(serialize = "orderedItems"))]
-    pub ordered_items: Vec<ActivityNoteItem>,
+    pub id: String,
+    pub actor: String,
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
+    pub object: ActivityObject,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct InboxObject {
+#[derive(Serialize)]
+pub struct UpdatePersonActivity {
+    #[serde(rename(serialize = "@context"))]
+    pub context: String,
     pub r#type: String,
     pub id: String,
     pub actor: String,
-    pub object: String,
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
+    pub object: Person,
 }
 
-impl FromStr for InboxObject {
-    type Err = Void;
+#[derive(Serialize)]
+pub struct DeletePersonActivity {
+    #[serde(rename(serialize = "@context"))]
+    pub context: String,
+    pub id: String,
+    pub r#type: String,
+    pub actor: String,
+    pub to: Vec<String>,
+    pub object: String,
+}
 
-    fn from_str(s: &str) -> Result<Self, Self::Err> {
-        Ok(InboxObject {
-            r#type: "".to_string(),
-            id: "".to_string(),
-            actor: "".to_string(),
-            object: s.to_string(),
-        })
-    }
+// replaces a deleted object
+#[derive(Serialize)]
+pub struct Tombstone {
+    #[serde(rename(serialize = "@context"), skip_serializing_if = "Option::is_none")]
+    pub context: Option<String>,
+    pub id: String,
+    pub r#type: String,
+    #[serde(rename(serialize = "formerType"))]
+    pub former_type: String,
+    pub deleted: String,
 }
 
-#[derive(Debug, Deserialize)]
-pub struct InboxActivity {
+#[derive(Serialize)]
+pub struct DeleteActivity {
+    #[serde(rename(serialize = "@context"))]
+    pub context: String,
+    pub id: String,
     pub r#type: String,
+    pub actor: String,
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
+    pub object: Tombstone,
+}
+
+// Add and Remove of an object to a collection such as featured
+#[derive(Serialize)]
+pub struct CollectionActivity {
+    #[serde(rename(serialize = "@context"))]
+    pub context: String,
     pub id: String,
+    pub r#type: String,
     pub actor: String,
-    #[serde(deserialize_with = "string_or_struct")]
-    pub object: InboxObject,
-}
-
-// https://serde.rs/string-or-struct.html
-fn string_or_struct<'de, T, D>(deserializer: D) -> Result<T, D::Error>
-    where
-        T: Deserialize<'de> + FromStr<Err=Void>,
-        D: Deserializer<'de>
-{
-    struct StringOrStruct<T>(PhantomData<fn() -> T>);
-
-    impl<'de, T> Visitor<'de> for StringOrStruct<T>
-        where
-            T: Deserialize<'de> + FromStr<Err=Void>,
-    {
-        type Value = T;
-
-        fn expecting(&self, formatter: &mut Formatter) -> std::fmt::Result {
-            formatter.write_str("string or map")
-        }
+    pub to: Vec<String>,
+    pub cc: Vec<String>,
+    pub object: String,
+    pub target: String,
+}
 
-        fn visit_str<E>(self, v: &str) -> Result<Self::Value, E> where E: Error
-        {
-            Ok(FromStr::from_str(v).unwrap())
-        }
+#[derive(Serialize)]
+pub struct OrderedCollection<T: Serialize> {
+    #[serde(rename(serialize = "@context"))]
+    pub context: String,
+    pub id: String,
+    pub r#type: String,
+    #[serde(rename(serialize = "totalItems"))]
+    pub total_items: usize,
+    // omitted when the members are hidden
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub first: Option<String>,
+    // small collections list the members inline
+    #[serde(rename(serialize = "orderedItems"), skip_serializing_if = "Option::is_none")]
+    pub ordered_items: Option<Vec<T>>,
+}
 
-        fn visit_map<A>(self, map: A) -> Result<Self::Value, A::Error> where A: MapAccess<'de> {
-            Deserialize::deserialize(de::value::MapAccessDeserializer::new(map))
-        }
-    }
+#[derive(Serialize)]
+pub struct OrderedCollectionPage<T: Serialize> {
+    #[serde(rename(serialize = "@context"))]
+    pub context: String,
+    pub id: String,
+    pub r#type: String,
+    #[serde(rename(serialize = "totalItems"))]
+    pub total_items: usize,
+    #[serde(rename(serialize = "partOf"))]
+    pub part_of: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub prev: Option<String>,
+    #[serde(rename(serialize = "orderedItems"))]
+    pub ordered_items: Vec<T>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RemotePublicKey {
+    pub id: String,
+    pub owner: String,
+    #[serde(rename = "publicKeyPem")]
+    pub public_key_pem: String,
+}
 
-    deserializer.deserialize_any(StringOrStruct(PhantomData))
+#[derive(Debug, Deserialize)]
+pub struct RemoteActorEndpoints {
+    #[serde(rename = "sharedInbox")]
+    pub shared_inbox: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct RemoteActorDocument {
+    pub id: String,
+    #[serde(rename = "preferredUsername")]
+    pub preferred_username: Option<String>,
+    pub inbox: String,
+    pub endpoints: Option<RemoteActorEndpoints>,
+    #[serde(rename = "publicKey")]
+    pub public_key: RemotePublicKey,
+}
+
+// keyId points to an actor (Mastodon, Misskey, ...) or to a key document itself
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum RemoteKeyDocument {
+    Actor(RemoteActorDocument),
+    Key(RemotePublicKey),
 }
 
 #[derive(Serialize)]
 pub struct FollowAcceptObject {
+    pub id: String,
     pub r#type: String,
     pub actor: String,
     pub object: String,
@@ -239,3 +376,32 @@ pub struct FollowAccept {
     pub actor: String,
     pub object: FollowAcceptObject,
 }
+
+// follow shapes are also used for Block and Undo{Block}, only the type differs
+#[derive(Serialize)]
+pub struct FollowObject {
+    pub id: String,
+    pub r#type: String,
+    pub actor: String,
+    pub object: String,
+}
+
+#[derive(Serialize)]
+pub struct FollowActivity {
+    #[serde(rename(serialize = "@context"))]
+    pub context: String,
+    pub id: String,
+    pub r#type: String,
+    pub actor: String,
+    pub object: String,
+}
+
+#[derive(Serialize)]
+pub struct UndoFollowActivity {
+    #[serde(rename(serialize = "@context"))]
+    pub context: String,
+    pub id: String,
+    pub r#type: String,
+    pub actor: String,
+    pub object: FollowObject,
+}
@@ -1,18 +1,22 @@
 use std::sync::Arc;
-use std::time::SystemTime;
-use actix_web::http::header::Date;
-use awc::Client;
-use base64::{Engine as _, engine::general_purpose};
 use chrono::Utc;
+use serde::Serialize;
 use serde_json::json;
-use sha256::digest;
 use url::Url;
 use crate::domain::activity_pub::activity_pub::*;
+use crate::domain::activity_pub::activity_streams::InboxActivity;
+use crate::domain::activity_pub::http_signature::{InboxRequest, SignatureHeader};
+use crate::domain::block::block::Block;
+use crate::domain::delivery::delivery_service::DeliveryService;
 use crate::domain::error::{CommonError, CommonErrorCode};
 use crate::domain::follower::follower::Follower;
-use crate::domain::note::note::Note;
+use crate::domain::following::following::Following;
+use crate::domain::note::note::{Note, NoteVisibility};
+use crate::domain::remote_actor::remote_actor::RemoteActor;
+use crate::domain::remote_actor::remote_actor_service::RemoteActorService;
 use crate::domain::user::user::User;
 use crate::domain::user::user_repository::UserRepository;
+use crate::domain::user::user_tombstone_repository::UserTombstoneRepository;
 
 const AP_HOST_META_TEMPLATE: &str = &r#"<?xml version="1.0"?>
 <XRD xmlns="http://docs.oasis-open.org/ns/xri/xrd-1.0">
@@ -21,12 +25,23 @@ const AP_HOST_META_TEMPLATE: &str = &r#"<?xml version="1.0"?>
 
 pub struct ActivityPubService {
     user_repository: Arc<dyn UserRepository>,
+    user_tombstone_repository: Arc<dyn UserTombstoneRepository>,
+    remote_actor_service: Arc<RemoteActorService>,
+    delivery_service: Arc<DeliveryService>,
 }
 
 impl ActivityPubService {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        user_tombstone_repository: Arc<dyn UserTombstoneRepository>,
+        remote_actor_service: Arc<RemoteActorService>,
+        delivery_service: Arc<DeliveryService>,
+    ) -> Self {
         ActivityPubService {
             user_repository,
+            user_tombstone_repository,
+            remote_actor_service,
+            delivery_service,
         }
     }
 
@@ -34,30 +49,24 @@ impl ActivityPubService {
         AP_HOST_META_TEMPLATE.replace("APP_URL", app_url).to_string()
     }
 
-    pub async fn web_finger(&self, resource: &str, app_url: &str) -> Result<WebFinger, ()> {
+    pub async fn web_finger(&self, resource: &str, app_url: &str) -> Result<WebFinger, CommonError> {
         // delete "acct:" in start
         let resource = if resource.starts_with("acct:") { &resource["acct:".len()..] } else { &resource };
 
         // check format as "hoge@foo.example.com"
         let elem: Vec<&str> = resource.split("@").collect();
         if elem.len() != 2 {
-            return Err(());
+            return Err(CommonError::new(CommonErrorCode::UserDoesNotExists));
         }
 
         // check domain
         let parsed_app_url = Url::parse(app_url).unwrap();
         if elem[1].to_string() != parsed_app_url.host().unwrap().to_string() {
-            return Err(());
+            return Err(CommonError::new(CommonErrorCode::UserDoesNotExists));
         }
 
         // resolve user id
-        let user = match self.user_repository.find(&elem[0]).await {
-            Ok(r) => match r {
-                Some(u) => u,
-                None => return Err(()),
-            },
-            Err(_) => return Err(()),
-        };
+        let user = self.find_user(&elem[0]).await?;
 
         Ok(WebFinger {
             subject: resource.to_string(),
@@ -100,147 +109,483 @@ impl ActivityPubService {
         }
     }
 
-    pub async fn actor(&self, username: &String, app_url: &String) -> Result<Person, CommonError> {
-        let user = match self.user_repository.find(username).await {
-            Ok(r) => match r {
-                Some(u) => u,
-                None => return Err(CommonError::new(CommonErrorCode::UserDoesNotExists)),
-            },
-            Err(e) => return Err(e),
-        };
-        Ok(Person {
+    pub async fn actor(&self, username: &String, app_url: &str) -> Result<Person, CommonError> {
+        let user = self.find_user(username).await?;
+        Ok(self.person(&user, app_url))
+    }
+
+    // deleted users are gone rather than missing
+    async fn find_user(&self, username: &str) -> Result<User, CommonError> {
+        if let Some(u) = self.user_repository.find(username).await? {
+            return Ok(u);
+        }
+        match self.user_tombstone_repository.find_by_username(username).await? {
+            Some(_) => Err(CommonError::new(CommonErrorCode::UserGone)),
+            None => Err(CommonError::new(CommonErrorCode::UserDoesNotExists)),
+        }
+    }
+
+    pub fn person(&self, user: &User, app_url: &str) -> Person {
+        Person {
             context: vec![
-                "https://www.w3.org/ns/activitystreams".to_string(),
-                "https://w3id.org/security/v1".to_string(),
+                json!("https://www.w3.org/ns/activitystreams"),
+                json!("https://w3id.org/security/v1"),
+                json!({
+                    "schema": "http://schema.org#",
+                    "PropertyValue": "schema:PropertyValue",
+                    "value": "schema:value",
+                }),
             ],
             id: format!("{}users/{}", app_url, user.id),
             r#type: "Person".to_string(),
-            preferred_username: user.username,
+            preferred_username: user.username.clone(),
+            name: user.display_name.clone(),
+            summary: user.summary.clone(),
+            url: format!("{}@{}", app_url, user.username),
+            published: user.created_at.to_rfc3339(),
+            icon: user.avatar_url.as_ref().map(|u| PersonImage { r#type: "Image".to_string(), url: u.clone() }),
+            image: user.header_url.as_ref().map(|u| PersonImage { r#type: "Image".to_string(), url: u.clone() }),
+            attachment: user.fields.iter()
+                .map(|f| PropertyValue {
+                    r#type: "PropertyValue".to_string(),
+                    name: f.name.clone(),
+                    value: f.value.clone(),
+                })
+                .collect(),
+            endpoints: PersonEndpoints {
+                shared_inbox: format!("{}inbox", app_url),
+            },
             inbox: format!("{}users/{}/inbox", app_url, user.id),
             outbox: format!("{}users/{}/outbox", app_url, user.id),
+            followers: format!("{}users/{}/followers", app_url, user.id),
+            following: format!("{}users/{}/following", app_url, user.id),
             shared_inbox: format!("{}inbox", app_url),
             public_key: PersonPublicKey {
                 id: format!("{}users/{}#main-key", app_url, user.id),
                 owner: format!("{}users/{}", app_url, user.id),
                 public_key_pem: String::from_utf8(user.key_pair.public_key.public_key_to_pem().unwrap()).unwrap(),
             },
-            featured: "".to_string(),
-            manually_approves_followers: false,
+            featured: featured_url(&user.id, app_url),
+            manually_approves_followers: user.locked,
             discoverable: false,
-        })
+        }
+    }
+
+    // remote servers refresh their cached profile and key
+    pub async fn send_update_person(&self, user: &User, recipients: Vec<Follower>, app_url: &str) -> Result<(), CommonError> {
+        let actor = format!("{}users/{}", app_url, user.id);
+        let update = UpdatePersonActivity {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            r#type: "Update".to_string(),
+            id: format!("{}#updates/{}", actor, user.updated_at.timestamp_millis()),
+            actor,
+            to: vec!["https://www.w3.org/ns/activitystreams#Public".to_string()],
+            cc: vec![format!("{}users/{}/followers", app_url, user.id)],
+            object: self.person(user, app_url),
+        };
+        let body = json!(update).to_string();
+
+        for inbox in self.delivery_inboxes(&recipients).await.iter() {
+            self.delivery_service.enqueue(user, inbox, &body).await?;
+        }
+
+        Ok(())
     }
 
     pub async fn get_redirect_url_to_username(&self, user_id: &String, app_url: &String) -> Result<String, CommonError> {
         let user = match self.user_repository.get(user_id).await {
             Ok(u) => u,
-            Err(e) => return Err(e),
+            Err(e) => {
+                return match self.user_tombstone_repository.find(user_id).await? {
+                    Some(_) => Err(CommonError::new(CommonErrorCode::UserGone)),
+                    None => Err(e),
+                };
+            }
         };
         Ok(format!("{}@{}", app_url, user.username))
     }
 
-    pub async fn send_note(&self, sender: &User, note: &Note, recipients: Vec<Follower>, app_url: &String) -> Result<(), CommonError> {
-        let item = ActivityNoteItem::new(&ActivityItemParams {
-            app_url: app_url.clone(),
-            user_id: sender.id.clone(),
+    pub fn note_item(&self, note: &Note, app_url: &str) -> ActivityNoteItem {
+        let (to, cc) = addressing(note, app_url);
+        ActivityNoteItem::new(&ActivityItemParams {
+            app_url: app_url.to_string(),
+            user_id: note.user_id.clone(),
             note_id: note.id.clone(),
             content: note.content.clone(),
-            published: Utc::now().to_rfc3339(),
-        });
+            published: note.created_at.to_rfc3339(),
+            updated: (note.updated_at > note.created_at).then(|| note.updated_at.to_rfc3339()),
+            to,
+            cc,
+        })
+    }
+
+    // returns the inboxes the note is delivered to
+    pub async fn send_note(&self, sender: &User, note: &Note, recipients: Vec<Follower>, app_url: &str) -> Result<Vec<String>, CommonError> {
+        let item = self.note_item(note, app_url);
         let body = json!(item).to_string();
 
-        let now = Date(SystemTime::now().into());
-        for r in recipients.iter() {
+        let inboxes = self.delivery_inboxes(&recipients).await;
+        for inbox in inboxes.iter() {
+            self.delivery_service.enqueue(sender, inbox, &body).await?;
+        }
+
+        Ok(inboxes)
+    }
 
-            // http signature
-            let parsed_url = Url::parse(&r.inbox).unwrap();
-            let digest_header = http_digest_header(&body);
-            let signature_data = format!(
-                "(request-target): post {}\nhost: {}\ndate: {}\ndigest: {}",
-                parsed_url.path(), parsed_url.host_str().unwrap(), now, digest_header
-            );
-            let signature = sender.sign(signature_data.as_bytes());
-
-            // send note
-            let req = Client::default().post(&r.inbox)
-                .insert_header(("Host", parsed_url.host_str().unwrap()))
-                .insert_header(now.clone())
-                .insert_header(("Digest", digest_header))
-                .insert_header(("Content-Type", "application/activity+json; charset=utf-8"))
-                .insert_header((
-                    "Signature",
-                    format!(
-                        "keyId=\"{}users/{}#main-key\",algorithm=\"rsa-sha256\",headers=\"(request-target) host date digest\",signature=\"{}\"",
-                        app_url, sender.id, signature
-                    )
-                ));
-            let _ = req.send_body(body.clone()).await;
+    // sent at once since the key is discarded with the user
+    pub async fn send_delete_person(&self, user: &User, followers: Vec<Follower>, followings: Vec<Following>, app_url: &str) -> Result<(), CommonError> {
+        let actor = format!("{}users/{}", app_url, user.id);
+        let delete = DeletePersonActivity {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: format!("{}#delete", actor),
+            r#type: "Delete".to_string(),
+            actor: actor.clone(),
+            to: vec!["https://www.w3.org/ns/activitystreams#Public".to_string()],
+            object: actor,
+        };
+        let body = json!(delete).to_string();
+
+        // servers of followed accounts also know the user
+        let mut inboxes = self.delivery_inboxes(&followers).await;
+        for f in followings.iter() {
+            let inbox = match self.remote_actor_service.find_cached(&f.actor).await {
+                Ok(Some(a)) => a.shared_inbox.filter(|s| !s.is_empty()).unwrap_or(a.inbox),
+                _ => continue,
+            };
+            if !inboxes.contains(&inbox) {
+                inboxes.push(inbox);
+            }
         }
 
+        let delivered = self.delivery_service.deliver_now(user, &inboxes, &body).await;
+        log::info!("Delete of user {} delivered to {}/{} inboxes", user.id, delivered, inboxes.len());
         Ok(())
     }
 
-    pub async fn send_follow_accept(&self, user: &User, activity: &InboxActivity, app_url: &String) -> Result<(), CommonError> {
-        let accept = FollowAccept {
+    // returns the inboxes the note is delivered to
+    pub async fn send_update_note(&self, sender: &User, note: &Note, recipients: Vec<Follower>, app_url: &str) -> Result<Vec<String>, CommonError> {
+        let item = self.note_item(note, app_url);
+        let update = UpdateNoteActivity {
             context: "https://www.w3.org/ns/activitystreams".to_string(),
-            summary: "Accepted".to_string(),
-            r#type: "Accept".to_string(),
+            r#type: "Update".to_string(),
+            // each edit is a distinct activity
+            id: format!("{}#updates/{}", item.object.id, note.updated_at.timestamp_millis()),
+            actor: item.actor,
+            to: item.to,
+            cc: item.cc,
+            object: item.object,
+        };
+        let body = json!(update).to_string();
+
+        let inboxes = self.delivery_inboxes(&recipients).await;
+        for inbox in inboxes.iter() {
+            self.delivery_service.enqueue(sender, inbox, &body).await?;
+        }
+
+        Ok(inboxes)
+    }
+
+    // members are listed on pages starting from 1
+    pub fn collection<T: Serialize>(&self, id: &str, total_items: usize, hidden: bool) -> OrderedCollection<T> {
+        OrderedCollection {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: id.to_string(),
+            r#type: "OrderedCollection".to_string(),
+            total_items,
+            first: if hidden { None } else { Some(format!("{}?page=1", id)) },
+            ordered_items: None,
+        }
+    }
+
+    // pinned notes, listed without pages
+    pub fn featured_collection(&self, user: &User, notes: &[Note], app_url: &str) -> OrderedCollection<ActivityObject> {
+        OrderedCollection {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: featured_url(&user.id, app_url),
+            r#type: "OrderedCollection".to_string(),
+            total_items: notes.len(),
+            first: None,
+            ordered_items: Some(notes.iter().map(|n| self.note_item(n, app_url).object).collect()),
+        }
+    }
+
+    pub fn collection_page<T: Serialize>(&self, id: &str, page: u64, page_size: u64, total_items: usize, items: Vec<T>) -> OrderedCollectionPage<T> {
+        OrderedCollectionPage {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: format!("{}?page={}", id, page),
+            r#type: "OrderedCollectionPage".to_string(),
+            total_items,
+            part_of: id.to_string(),
+            next: if page.saturating_mul(page_size) < total_items as u64 { Some(format!("{}?page={}", id, page + 1)) } else { None },
+            prev: if page > 1 { Some(format!("{}?page={}", id, page - 1)) } else { None },
+            ordered_items: items,
+        }
+    }
+
+    pub fn note_tombstone(&self, note: &Note, app_url: &str) -> Tombstone {
+        Tombstone {
+            context: Some("https://www.w3.org/ns/activitystreams".to_string()),
+            id: format!("{}notes/{}", app_url, note.id),
+            r#type: "Tombstone".to_string(),
+            former_type: "Note".to_string(),
+            deleted: note.updated_at.to_rfc3339(),
+        }
+    }
+
+    // delivered_to are the inboxes which received the note, former followers also remove their copy
+    pub async fn send_delete_note(&self, sender: &User, note: &Note, recipients: Vec<Follower>, delivered_to: Vec<String>, app_url: &str) -> Result<(), CommonError> {
+        let (to, cc) = addressing(note, app_url);
+        let delete = DeleteActivity {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: format!("{}notes/{}#delete", app_url, note.id),
+            r#type: "Delete".to_string(),
+            actor: format!("{}users/{}", app_url, sender.id),
+            to,
+            cc,
+            object: Tombstone {
+                context: None,
+                ..self.note_tombstone(note, app_url)
+            },
+        };
+        let body = json!(delete).to_string();
+
+        let mut inboxes = self.delivery_inboxes(&recipients).await;
+        for inbox in delivered_to.into_iter() {
+            if !inboxes.contains(&inbox) {
+                inboxes.push(inbox);
+            }
+        }
+        for inbox in inboxes.iter() {
+            self.delivery_service.enqueue(sender, inbox, &body).await?;
+        }
+
+        Ok(())
+    }
+
+    // remote servers update the pinned notes on the profile
+    pub async fn send_add_featured(&self, sender: &User, note: &Note, recipients: Vec<Follower>, app_url: &str) -> Result<(), CommonError> {
+        let pinned_at = note.pinned_at.unwrap_or_else(Utc::now);
+        let id = format!("{}notes/{}#pin-{}", app_url, note.id, pinned_at.timestamp());
+        self.send_featured_activity(sender, note, "Add", &id, recipients, app_url).await
+    }
+
+    pub async fn send_remove_featured(&self, sender: &User, note: &Note, recipients: Vec<Follower>, app_url: &str) -> Result<(), CommonError> {
+        let id = format!("{}notes/{}#unpin-{}", app_url, note.id, Utc::now().timestamp());
+        self.send_featured_activity(sender, note, "Remove", &id, recipients, app_url).await
+    }
+
+    async fn send_featured_activity(&self, sender: &User, note: &Note, r#type: &str, id: &str, recipients: Vec<Follower>, app_url: &str) -> Result<(), CommonError> {
+        let activity = CollectionActivity {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: id.to_string(),
+            r#type: r#type.to_string(),
+            actor: format!("{}users/{}", app_url, sender.id),
+            to: vec!["https://www.w3.org/ns/activitystreams#Public".to_string()],
+            cc: vec![format!("{}users/{}/followers", app_url, sender.id)],
+            object: format!("{}notes/{}", app_url, note.id),
+            target: featured_url(&sender.id, app_url),
+        };
+        let body = json!(activity).to_string();
+
+        for inbox in self.delivery_inboxes(&recipients).await.iter() {
+            self.delivery_service.enqueue(sender, inbox, &body).await?;
+        }
+
+        Ok(())
+    }
+
+    // followers on the same server receive a single delivery through their sharedInbox
+    async fn delivery_inboxes(&self, recipients: &[Follower]) -> Vec<String> {
+        let mut inboxes: Vec<String> = Vec::new();
+        for r in recipients.iter() {
+            let shared_inbox = match self.remote_actor_service.find_cached(&r.actor).await {
+                Ok(Some(a)) => a.shared_inbox.filter(|s| !s.is_empty()),
+                _ => None,
+            };
+            let inbox = shared_inbox.unwrap_or(r.inbox.clone());
+            if !inboxes.contains(&inbox) {
+                inboxes.push(inbox);
+            }
+        }
+        inboxes
+    }
+
+    pub async fn send_follow_accept(&self, user: &User, activity: &InboxActivity, inbox: &str, app_url: &str) -> Result<(), CommonError> {
+        self.send_follow_reply(user, "Accept", &activity.id, &activity.actor, inbox, app_url).await
+    }
+
+    // reply to a Follow received by local user, follow_id is the id of the Follow activity
+    pub async fn send_follow_reply(&self, user: &User, r#type: &str, follow_id: &str, follower: &str, inbox: &str, app_url: &str) -> Result<(), CommonError> {
+        let reply = FollowAccept {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            summary: format!("{}ed", r#type),
+            r#type: r#type.to_string(),
             actor: format!("{}users/{}", app_url, user.id),
             object: FollowAcceptObject {
-                r#type: activity.r#type.clone(),
-                actor: activity.actor.clone(),
-                object: activity.object.object.clone(),
+                id: follow_id.to_string(),
+                r#type: "Follow".to_string(),
+                actor: follower.to_string(),
+                object: format!("{}users/{}", app_url, user.id),
             },
         };
-        let body = json!(accept).to_string();
-
-        // http signature
-        let url = format!("{}/inbox", activity.actor);
-        let parsed_url = Url::parse(&url).unwrap();
-        let now = Date(SystemTime::now().into());
-        let digest_header = http_digest_header(&body);
-        let signature_data = format!(
-            "(request-target): post {}\nhost: {}\ndate: {}\ndigest: {}",
-            parsed_url.path(), parsed_url.host_str().unwrap(), now, digest_header
-        );
-        let signature = user.sign(signature_data.as_bytes());
-
-        // send accept
-        let accept_req = Client::default().post(url)
-            .insert_header(("Host", parsed_url.host_str().unwrap()))
-            .insert_header(now)
-            .insert_header(("Digest", digest_header))
-            .insert_header(("Content-Type", "application/activity+json; charset=utf-8"))
-            .insert_header((
-                "Signature",
-                format!(
-                    "keyId=\"{}users/{}#main-key\",algorithm=\"rsa-sha256\",headers=\"(request-target) host date digest\",signature=\"{}\"",
-                    app_url, user.id, signature
-                )
-            ));
-        let _ = accept_req.send_body(body).await;
-        Ok(())
+        let body = json!(reply).to_string();
+
+        self.delivery_service.enqueue(user, inbox, &body).await
+    }
+
+    pub async fn send_follow(&self, user: &User, following: &Following, inbox: &str, app_url: &str) -> Result<(), CommonError> {
+        let follow = FollowActivity {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: following.object.clone(),
+            r#type: "Follow".to_string(),
+            actor: format!("{}users/{}", app_url, user.id),
+            object: following.actor.clone(),
+        };
+        let body = json!(follow).to_string();
+
+        self.delivery_service.enqueue(user, inbox, &body).await
+    }
+
+    pub async fn send_undo_follow(&self, user: &User, following: &Following, inbox: &str, app_url: &str) -> Result<(), CommonError> {
+        let undo = UndoFollowActivity {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: format!("{}/undo", following.object),
+            r#type: "Undo".to_string(),
+            actor: format!("{}users/{}", app_url, user.id),
+            object: FollowObject {
+                id: following.object.clone(),
+                r#type: "Follow".to_string(),
+                actor: format!("{}users/{}", app_url, user.id),
+                object: following.actor.clone(),
+            },
+        };
+        let body = json!(undo).to_string();
+
+        self.delivery_service.enqueue(user, inbox, &body).await
+    }
+
+    pub async fn send_block(&self, user: &User, block: &Block, inbox: &str, app_url: &str) -> Result<(), CommonError> {
+        let activity = FollowActivity {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: block.object.clone(),
+            r#type: "Block".to_string(),
+            actor: format!("{}users/{}", app_url, user.id),
+            object: block.actor.clone(),
+        };
+        let body = json!(activity).to_string();
+
+        self.delivery_service.enqueue(user, inbox, &body).await
+    }
+
+    pub async fn send_undo_block(&self, user: &User, block: &Block, inbox: &str, app_url: &str) -> Result<(), CommonError> {
+        let undo = UndoFollowActivity {
+            context: "https://www.w3.org/ns/activitystreams".to_string(),
+            id: format!("{}/undo", block.object),
+            r#type: "Undo".to_string(),
+            actor: format!("{}users/{}", app_url, user.id),
+            object: FollowObject {
+                id: block.object.clone(),
+                r#type: "Block".to_string(),
+                actor: format!("{}users/{}", app_url, user.id),
+                object: block.actor.clone(),
+            },
+        };
+        let body = json!(undo).to_string();
+
+        self.delivery_service.enqueue(user, inbox, &body).await
+    }
+
+    // returns owner of the key used to sign the request
+    pub async fn verify_inbox_request(&self, request: &InboxRequest, max_clock_skew: i64) -> Result<RemoteActor, CommonError> {
+        // signature must cover the request target, date and body
+        self.verify_signed_request(request, &["(request-target)", "date", "digest"], max_clock_skew).await
+    }
+
+    // signed GET of an object (authorized fetch), returns the requesting actor
+    pub async fn verify_fetch_request(&self, request: &InboxRequest, max_clock_skew: i64) -> Result<RemoteActor, CommonError> {
+        self.verify_signed_request(request, &["(request-target)", "date"], max_clock_skew).await
+    }
+
+    async fn verify_signed_request(&self, request: &InboxRequest, required: &[&str], max_clock_skew: i64) -> Result<RemoteActor, CommonError> {
+        let signature = match request.header("signature") {
+            Some(s) => SignatureHeader::parse(s)?,
+            None => return Err(CommonError::new(CommonErrorCode::InvalidSignature)),
+        };
+
+        for h in required {
+            if !signature.headers.iter().any(|s| s == h) {
+                log::warn!("Signature does not cover {}", h);
+                return Err(CommonError::new(CommonErrorCode::InvalidSignature));
+            }
+        }
+        if required.contains(&"digest") && !request.verify_digest() {
+            log::warn!("Digest mismatch");
+            return Err(CommonError::new(CommonErrorCode::InvalidSignature));
+        }
+        if !request.verify_date(Utc::now(), max_clock_skew) {
+            log::warn!("Date is out of the allowed range: {:?}", request.header("date"));
+            return Err(CommonError::new(CommonErrorCode::InvalidSignature));
+        }
+        let signing_string = request.signing_string(&signature.headers)?;
+
+        let actor = match self.remote_actor_service.resolve_by_key_id(&signature.key_id, false).await {
+            Ok(a) => a,
+            Err(_) => return Err(CommonError::new(CommonErrorCode::InvalidSignature)),
+        };
+        if signature.verify(&signing_string, &actor.public_key_pem) {
+            return Ok(actor);
+        }
+
+        // cached key may be rotated
+        let actor = match self.remote_actor_service.resolve_by_key_id(&signature.key_id, true).await {
+            Ok(a) => a,
+            Err(_) => return Err(CommonError::new(CommonErrorCode::InvalidSignature)),
+        };
+        if !signature.verify(&signing_string, &actor.public_key_pem) {
+            log::warn!("Signature mismatch: keyId={}", signature.key_id);
+            return Err(CommonError::new(CommonErrorCode::InvalidSignature));
+        }
+        Ok(actor)
     }
 }
 
-fn http_digest_header(data: &String) -> String {
-    let sha256_hash = digest(data);
-    let binaries = sha256_hash.chars()
-        .collect::<Vec<char>>()
-        .chunks(2)
-        .map(|c| c.iter().collect::<String>())
-        .map(|hex| u8::from_str_radix(&hex, 16).unwrap())
-        .collect::<Vec<u8>>();
-    format!("SHA-256={}", general_purpose::STANDARD.encode(binaries))
+fn featured_url(user_id: &str, app_url: &str) -> String {
+    format!("{}users/{}/collections/featured", app_url, user_id)
+}
+
+// (to, cc) of a note and activities about it
+fn addressing(note: &Note, app_url: &str) -> (Vec<String>, Vec<String>) {
+    let public = "https://www.w3.org/ns/activitystreams#Public".to_string();
+    let followers = format!("{}users/{}/followers", app_url, note.user_id);
+    match note.visibility {
+        NoteVisibility::UNLISTED => (vec![followers], vec![public]),
+        NoteVisibility::FOLLOWERS => (vec![followers], vec![]),
+        _ => (vec![public], vec![followers]),
+    }
 }
 
 #[cfg(test)]
 mod test {
     use std::sync::Arc;
     use async_trait::async_trait;
+    use chrono::{DateTime, Utc};
     use crate::domain::activity_pub::activity_pub_service::ActivityPubService;
+    use crate::domain::app_config::AppConfig;
+    use crate::domain::delivery::delivery_job::DeliveryJob;
+    use crate::domain::delivery::delivery_job_repository::DeliveryJobRepository;
+    use crate::domain::delivery::delivery_service::DeliveryService;
     use crate::domain::error::{CommonError, CommonErrorCode};
+    use crate::domain::follower::follower::Follower;
+    use crate::domain::instance::instance_health::InstanceHealth;
+    use crate::domain::instance::instance_health_repository::InstanceHealthRepository;
+    use crate::domain::instance::instance_service::InstanceService;
+    use crate::domain::remote_actor::remote_actor::RemoteActor;
+    use crate::domain::remote_actor::remote_actor_repository::RemoteActorRepository;
+    use crate::domain::remote_actor::remote_actor_service::RemoteActorService;
     use crate::domain::user::user::User;
     use crate::domain::user::user_repository::UserRepository;
+    use crate::domain::user::user_tombstone::UserTombstone;
+    use crate::domain::user::user_tombstone_repository::UserTombstoneRepository;
 
     struct MockUserRepository {}
 
@@ -275,11 +620,130 @@ mod test {
         }
     }
 
+    struct MockUserTombstoneRepository {}
+
+    #[async_trait]
+    impl UserTombstoneRepository for MockUserTombstoneRepository {
+        async fn add(&self, _tombstone: &UserTombstone) -> Result<(), CommonError> {
+            todo!()
+        }
+
+        async fn find(&self, _user_id: &str) -> Result<Option<UserTombstone>, CommonError> {
+            Ok(None)
+        }
+
+        async fn find_by_username(&self, _username: &str) -> Result<Option<UserTombstone>, CommonError> {
+            Ok(None)
+        }
+    }
+
+    struct MockRemoteActorRepository {}
+
+    #[async_trait]
+    impl RemoteActorRepository for MockRemoteActorRepository {
+        async fn find(&self, actor_id: &str) -> Result<Option<RemoteActor>, CommonError> {
+            let shared_inbox = if actor_id.starts_with("https://remote.example.com/") {
+                Some("https://remote.example.com/inbox".to_string())
+            } else if actor_id.starts_with("https://other.example.com/") {
+                None
+            } else {
+                return Ok(None);
+            };
+            Ok(Some(RemoteActor {
+                id: actor_id.to_string(),
+                preferred_username: "".to_string(),
+                inbox: format!("{}/inbox", actor_id),
+                shared_inbox,
+                public_key_id: format!("{}#main-key", actor_id),
+                public_key_pem: "".to_string(),
+                fetched_at: Utc::now(),
+            }))
+        }
+
+        async fn find_by_key_id(&self, _key_id: &str) -> Result<Option<RemoteActor>, CommonError> {
+            todo!()
+        }
+
+        async fn save(&self, _actor: &RemoteActor) -> Result<(), CommonError> {
+            todo!()
+        }
+    }
+
+    struct MockDeliveryJobRepository {}
+
+    #[async_trait]
+    impl DeliveryJobRepository for MockDeliveryJobRepository {
+        async fn add(&self, _new_job: &DeliveryJob) -> Result<(), CommonError> {
+            todo!()
+        }
+
+        async fn list_due(&self, _now: DateTime<Utc>, _limit: u64) -> Result<Vec<DeliveryJob>, CommonError> {
+            todo!()
+        }
+
+        async fn update(&self, _job: &DeliveryJob) -> Result<(), CommonError> {
+            todo!()
+        }
+
+        async fn resume_host(&self, _host: &str, _now: DateTime<Utc>) -> Result<(), CommonError> {
+            todo!()
+        }
+
+        async fn delete_finished_before(&self, _cutoff: DateTime<Utc>) -> Result<(), CommonError> {
+            todo!()
+        }
+    }
+
+    struct MockInstanceHealthRepository {}
+
+    #[async_trait]
+    impl InstanceHealthRepository for MockInstanceHealthRepository {
+        async fn find(&self, _host: &str) -> Result<Option<InstanceHealth>, CommonError> {
+            todo!()
+        }
+
+        async fn list(&self) -> Result<Vec<InstanceHealth>, CommonError> {
+            todo!()
+        }
+
+        async fn save(&self, _health: &InstanceHealth) -> Result<(), CommonError> {
+            todo!()
+        }
+    }
+
+    fn activity_pub_service() -> ActivityPubService {
+        let app_config = Arc::new(AppConfig {
+            environment: "test".to_string(),
+            app_url: "https://test.example.com/".to_string(),
+            app_url_host: "test.example.com".to_string(),
+            admin_api_key: "".to_string(),
+            database_url: "".to_string(),
+            signature_clock_skew: 300,
+            processed_activity_retention: 604800,
+            delivery_max_attempts: 10,
+            delivery_retry_base: 60,
+            delivery_concurrency: 16,
+            delivery_host_concurrency: 2,
+            delivery_timeout: 10,
+            delivery_job_retention: 604800,
+            instance_unreachable_threshold: 604800,
+        });
+        ActivityPubService {
+            user_repository: Arc::new(MockUserRepository {}),
+            user_tombstone_repository: Arc::new(MockUserTombstoneRepository {}),
+            remote_actor_service: Arc::new(RemoteActorService::new(Arc::new(MockRemoteActorRepository {}))),
+            delivery_service: Arc::new(DeliveryService::new(
+                app_config,
+                Arc::new(MockDeliveryJobRepository {}),
+                Arc::new(MockUserRepository {}),
+                Arc::new(InstanceService::new(604800, Arc::new(MockInstanceHealthRepository {}), Arc::new(MockDeliveryJobRepository {}))),
+            )),
+        }
+    }
+
     #[actix_web::test]
     async fn web_finger() {
-        let service = ActivityPubService {
-            user_repository: Arc::new(MockUserRepository {}),
-        };
+        let service = activity_pub_service();
         let app_url = "https://test.example.com/";
 
         assert!(service.web_finger("acct:hoge@test.example.com", app_url).await.is_ok());
@@ -288,4 +752,50 @@ mod test {
         assert!(service.web_finger("hogehoge", app_url).await.is_err());
         assert!(service.web_finger("", app_url).await.is_err());
     }
+
+    #[test]
+    fn collection() {
+        let service = activity_pub_service();
+        let id = "https://test.example.com/users/abcd1234/followers";
+
+        let collection = service.collection::<String>(id, 3, false);
+        assert_eq!(collection.total_items, 3);
+        assert_eq!(collection.first, Some(format!("{}?page=1", id)));
+        assert!(collection.ordered_items.is_none());
+        assert_eq!(service.collection::<String>(id, 3, true).first, None);
+
+        let page = service.collection_page(id, 1, 2, 3, vec!["a", "b"]);
+        assert_eq!(page.id, format!("{}?page=1", id));
+        assert_eq!(page.part_of, id);
+        assert_eq!(page.next, Some(format!("{}?page=2", id)));
+        assert_eq!(page.prev, None);
+
+        let page = service.collection_page(id, 2, 2, 3, vec!["c"]);
+        assert_eq!(page.next, None);
+        assert_eq!(page.prev, Some(format!("{}?page=1", id)));
+
+        let page = service.collection_page::<String>(id, u64::MAX, 2, 3, vec![]);
+        assert_eq!(page.next, None);
+        assert_eq!(page.prev, Some(format!("{}?page={}", id, u64::MAX - 1)));
+    }
+
+    #[actix_web::test]
+    async fn delivery_inboxes() {
+        let service = activity_pub_service();
+        let followers = vec![
+            Follower::new("abcd1234", "https://remote.example.com/users/foo", "", "https://remote.example.com/users/foo/inbox"),
+            Follower::new("abcd1234", "https://remote.example.com/users/bar", "", "https://remote.example.com/users/bar/inbox"),
+            Follower::new("abcd1234", "https://other.example.com/users/foo", "", "https://other.example.com/users/foo/inbox"),
+            Follower::new("abcd1234", "https://unknown.example.com/users/foo", "", "https://unknown.example.com/users/foo/inbox"),
+        ];
+
+        assert_eq!(
+            service.delivery_inboxes(&followers).await,
+            vec![
+                "https://remote.example.com/inbox",
+                "https://other.example.com/users/foo/inbox",
+                "https://unknown.example.com/users/foo/inbox",
+            ]
+        );
+    }
 }
@@ -0,0 +1,366 @@
+use serde::{de, Deserialize, Deserializer};
+use serde_json::{Map, Value};
+
+// https://www.w3.org/TR/activitystreams-vocabulary/#activity-types
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum ActivityType {
+    Accept,
+    Add,
+    Announce,
+    Block,
+    Create,
+    Delete,
+    Follow,
+    Like,
+    Reject,
+    Remove,
+    Undo,
+    Update,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+impl ActivityType {
+    fn parse(value: &str) -> Self {
+        serde_json::from_value(Value::String(value.to_string())).unwrap_or_default()
+    }
+}
+
+// https://www.w3.org/TR/activitystreams-vocabulary/#object-types
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum ObjectType {
+    Article,
+    Audio,
+    Document,
+    Event,
+    Image,
+    Note,
+    Page,
+    Question,
+    Tombstone,
+    Video,
+    // actors
+    Application,
+    Group,
+    Organization,
+    Person,
+    Service,
+    // collections
+    Collection,
+    CollectionPage,
+    OrderedCollection,
+    OrderedCollectionPage,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+impl ObjectType {
+    pub fn is_actor(&self) -> bool {
+        matches!(self, ObjectType::Application | ObjectType::Group | ObjectType::Organization | ObjectType::Person | ObjectType::Service)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Deserialize)]
+pub enum TagType {
+    Emoji,
+    Hashtag,
+    Mention,
+    #[default]
+    #[serde(other)]
+    Unknown,
+}
+
+// "@context": "https://www.w3.org/ns/activitystreams", {...} or a list of them
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+pub enum Context {
+    Uri(String),
+    Definition(Map<String, Value>),
+    List(Vec<Context>),
+}
+
+#[derive(Debug, Deserialize)]
+pub struct InboxActivity {
+    #[serde(default, rename = "@context")]
+    pub context: Option<Context>,
+    #[serde(default)]
+    pub r#type: ActivityType,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, deserialize_with = "uri")]
+    pub actor: String,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub object: Vec<ObjectRef>,
+    #[serde(default, deserialize_with = "optional_uri")]
+    pub target: Option<String>,
+    #[serde(default, deserialize_with = "uris")]
+    pub to: Vec<String>,
+    #[serde(default, deserialize_with = "uris")]
+    pub cc: Vec<String>,
+    #[serde(default, deserialize_with = "uris")]
+    pub bto: Vec<String>,
+    #[serde(default, deserialize_with = "uris")]
+    pub bcc: Vec<String>,
+    pub published: Option<String>,
+}
+
+impl InboxActivity {
+    // activities handled here take a single object
+    pub fn object(&self) -> Option<&ObjectRef> {
+        self.object.first()
+    }
+
+    pub fn object_id(&self) -> &str {
+        self.object().map(|o| o.id()).unwrap_or_default()
+    }
+
+    pub fn recipients(&self) -> impl Iterator<Item=&String> {
+        self.to.iter().chain(self.cc.iter()).chain(self.bto.iter()).chain(self.bcc.iter())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InboxObject {
+    #[serde(default)]
+    pub r#type: ObjectType,
+    #[serde(default)]
+    pub id: String,
+    #[serde(default, deserialize_with = "uris")]
+    pub attributed_to: Vec<String>,
+    pub content: Option<String>,
+    pub summary: Option<String>,
+    #[serde(default, deserialize_with = "optional_uri")]
+    pub in_reply_to: Option<String>,
+    pub published: Option<String>,
+    #[serde(default, deserialize_with = "uris")]
+    pub to: Vec<String>,
+    #[serde(default, deserialize_with = "uris")]
+    pub cc: Vec<String>,
+    #[serde(default, deserialize_with = "uris")]
+    pub bto: Vec<String>,
+    #[serde(default, deserialize_with = "uris")]
+    pub bcc: Vec<String>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub tag: Vec<Tag>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub attachment: Vec<Attachment>,
+    // embedded actor
+    pub preferred_username: Option<String>,
+    pub inbox: Option<String>,
+}
+
+impl InboxObject {
+    pub fn recipients(&self) -> impl Iterator<Item=&String> {
+        self.to.iter().chain(self.cc.iter()).chain(self.bto.iter()).chain(self.bcc.iter())
+    }
+}
+
+#[derive(Debug, Deserialize)]
+pub struct Tag {
+    #[serde(default)]
+    pub r#type: TagType,
+    pub href: Option<String>,
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Attachment {
+    #[serde(default)]
+    pub r#type: ObjectType,
+    pub media_type: Option<String>,
+    #[serde(default, deserialize_with = "optional_uri")]
+    pub url: Option<String>,
+    pub name: Option<String>,
+}
+
+// object of an activity: uri only, embedded activity (e.g. Undo{Follow}) or embedded object
+#[derive(Debug)]
+pub enum ObjectRef {
+    Link(String),
+    Activity(Box<InboxActivity>),
+    Object(Box<InboxObject>),
+}
+
+impl ObjectRef {
+    pub fn id(&self) -> &str {
+        match self {
+            ObjectRef::Link(uri) => uri,
+            ObjectRef::Activity(a) => &a.id,
+            ObjectRef::Object(o) => &o.id,
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for ObjectRef {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where D: Deserializer<'de> {
+        let value = Value::deserialize(deserializer)?;
+        match value {
+            Value::String(uri) => Ok(ObjectRef::Link(uri)),
+            Value::Object(ref map) => {
+                let is_activity = map.get("type")
+                    .and_then(|t| t.as_str())
+                    .map(|t| ActivityType::parse(t) != ActivityType::Unknown)
+                    .unwrap_or(false);
+                if is_activity {
+                    InboxActivity::deserialize(value)
+                        .map(|a| ObjectRef::Activity(Box::new(a)))
+                        .map_err(de::Error::custom)
+                } else {
+                    InboxObject::deserialize(value)
+                        .map(|o| ObjectRef::Object(Box::new(o)))
+                        .map_err(de::Error::custom)
+                }
+            }
+            _ => Err(de::Error::custom("expected string or map")),
+        }
+    }
+}
+
+// "https://..." or an embedded object / link having "id" or "href"
+fn value_uri(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Object(map) => map.get("id").or(map.get("href")).and_then(|v| v.as_str()).map(|s| s.to_string()),
+        _ => None,
+    }
+}
+
+fn uri<'de, D>(deserializer: D) -> Result<String, D::Error>
+    where
+        D: Deserializer<'de>
+{
+    Ok(optional_uri(deserializer)?.unwrap_or_default())
+}
+
+fn optional_uri<'de, D>(deserializer: D) -> Result<Option<String>, D::Error>
+    where
+        D: Deserializer<'de>
+{
+    let value = Value::deserialize(deserializer)?;
+    match &value {
+        Value::Array(items) => Ok(items.iter().find_map(value_uri)),
+        v => Ok(value_uri(v)),
+    }
+}
+
+// "to": "https://..." or "to": ["https://...", {...}, ...]
+fn uris<'de, D>(deserializer: D) -> Result<Vec<String>, D::Error>
+    where
+        D: Deserializer<'de>
+{
+    let value = Value::deserialize(deserializer)?;
+    match &value {
+        Value::Array(items) => Ok(items.iter().filter_map(value_uri).collect()),
+        v => Ok(value_uri(v).into_iter().collect()),
+    }
+}
+
+fn one_or_many<'de, T, D>(deserializer: D) -> Result<Vec<T>, D::Error>
+    where
+        T: Deserialize<'de>,
+        D: Deserializer<'de>
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum OneOrMany<T> {
+        One(T),
+        Many(Vec<T>),
+    }
+
+    match Option::<OneOrMany<T>>::deserialize(deserializer)? {
+        Some(OneOrMany::One(v)) => Ok(vec![v]),
+        Some(OneOrMany::Many(v)) => Ok(v),
+        None => Ok(vec![]),
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::activity_pub::activity_streams::{ActivityType, Context, InboxActivity, ObjectRef, ObjectType, TagType};
+
+    #[test]
+    fn parse_create_note() {
+        let activity: InboxActivity = serde_json::from_str(r##"{
+            "@context": ["https://www.w3.org/ns/activitystreams", {"sensitive": "as:sensitive"}],
+            "id": "https://remote.example.com/users/foo/statuses/1/activity",
+            "type": "Create",
+            "actor": "https://remote.example.com/users/foo",
+            "to": "https://www.w3.org/ns/activitystreams#Public",
+            "cc": ["https://remote.example.com/users/foo/followers"],
+            "object": {
+                "id": "https://remote.example.com/users/foo/statuses/1",
+                "type": "Note",
+                "attributedTo": "https://remote.example.com/users/foo",
+                "content": "<p>Hello</p>",
+                "inReplyTo": null,
+                "tag": [
+                    {"type": "Mention", "href": "https://test.example.com/users/bar", "name": "@bar"},
+                    {"type": "Hashtag", "href": "https://remote.example.com/tags/hello", "name": "#hello"}
+                ],
+                "attachment": {"type": "Document", "mediaType": "image/png", "url": "https://remote.example.com/1.png"}
+            }
+        }"##).unwrap();
+
+        assert_eq!(activity.r#type, ActivityType::Create);
+        assert!(matches!(activity.context, Some(Context::List(ref l)) if l.len() == 2));
+        assert_eq!(activity.to, vec!["https://www.w3.org/ns/activitystreams#Public"]);
+        let note = match activity.object() {
+            Some(ObjectRef::Object(o)) => o,
+            o => panic!("unexpected object: {:?}", o),
+        };
+        assert_eq!(note.r#type, ObjectType::Note);
+        assert_eq!(note.attributed_to, vec!["https://remote.example.com/users/foo"]);
+        assert_eq!(note.in_reply_to, None);
+        assert_eq!(note.tag.len(), 2);
+        assert_eq!(note.tag[0].r#type, TagType::Mention);
+        assert_eq!(note.attachment.len(), 1);
+        assert_eq!(note.attachment[0].url.as_deref(), Some("https://remote.example.com/1.png"));
+    }
+
+    #[test]
+    fn parse_embedded_activity() {
+        let activity: InboxActivity = serde_json::from_str(r#"{
+            "@context": "https://www.w3.org/ns/activitystreams",
+            "id": "https://remote.example.com/users/foo#follows/1/undo",
+            "type": "Undo",
+            "actor": {"id": "https://remote.example.com/users/foo", "type": "Person"},
+            "object": {
+                "id": "https://remote.example.com/users/foo#follows/1",
+                "type": "Follow",
+                "actor": "https://remote.example.com/users/foo",
+                "object": "https://test.example.com/users/bar"
+            }
+        }"#).unwrap();
+
+        assert_eq!(activity.r#type, ActivityType::Undo);
+        assert_eq!(activity.actor, "https://remote.example.com/users/foo");
+        assert_eq!(activity.object_id(), "https://remote.example.com/users/foo#follows/1");
+        match activity.object() {
+            Some(ObjectRef::Activity(a)) => {
+                assert_eq!(a.r#type, ActivityType::Follow);
+                assert_eq!(a.object_id(), "https://test.example.com/users/bar");
+            }
+            o => panic!("unexpected object: {:?}", o),
+        }
+    }
+
+    #[test]
+    fn parse_link_and_unknown() {
+        let activity: InboxActivity = serde_json::from_str(r#"{
+            "id": "https://remote.example.com/activities/1",
+            "type": "EmojiReact",
+            "actor": "https://remote.example.com/users/foo",
+            "object": ["https://test.example.com/notes/1", {"type": "Bite"}]
+        }"#).unwrap();
+
+        assert_eq!(activity.r#type, ActivityType::Unknown);
+        assert!(activity.context.is_none());
+        assert_eq!(activity.object.len(), 2);
+        assert!(matches!(activity.object(), Some(ObjectRef::Link(l)) if l == "https://test.example.com/notes/1"));
+        assert!(matches!(&activity.object[1], ObjectRef::Object(o) if o.r#type == ObjectType::Unknown));
+    }
+}
@@ -0,0 +1,246 @@
+use std::collections::HashMap;
+use base64::{Engine as _, engine::general_purpose};
+use chrono::{DateTime, Utc};
+use openssl::hash::MessageDigest;
+use openssl::pkey::PKey;
+use openssl::rsa::Padding;
+use openssl::sign::Verifier;
+use sha256::digest;
+use crate::domain::error::{CommonError, CommonErrorCode};
+
+// request received by inbox
+pub struct InboxRequest {
+    pub method: String,
+    pub path: String,
+    // header names must be lowercase
+    pub headers: HashMap<String, String>,
+    pub body: String,
+}
+
+impl InboxRequest {
+    pub fn header(&self, name: &str) -> Option<&String> {
+        self.headers.get(name)
+    }
+
+    // https://datatracker.ietf.org/doc/html/draft-cavage-http-signatures-12#section-2.3
+    pub fn signing_string(&self, headers: &[String]) -> Result<String, CommonError> {
+        let mut lines = Vec::new();
+        for h in headers.iter() {
+            if h == "(request-target)" {
+                lines.push(format!("(request-target): {} {}", self.method.to_lowercase(), self.path));
+                continue;
+            }
+            match self.header(h) {
+                Some(v) => lines.push(format!("{}: {}", h, v)),
+                None => return Err(CommonError::new(CommonErrorCode::InvalidSignature)),
+            }
+        }
+        Ok(lines.join("\n"))
+    }
+
+    // compare Digest header with the hash of the body
+    pub fn verify_digest(&self) -> bool {
+        let expected = http_digest_header(&self.body);
+        match self.header("digest") {
+            // multiple digests may be listed, e.g. "SHA-256=...,SHA-512=..."
+            Some(v) => v.split(',')
+                .filter_map(|d| d.trim().split_once('='))
+                .any(|(algorithm, value)| {
+                    algorithm.eq_ignore_ascii_case("SHA-256") && format!("SHA-256={}", value) == expected
+                }),
+            None => false,
+        }
+    }
+
+    // check Date header is within the allowed clock skew
+    pub fn verify_date(&self, now: DateTime<Utc>, max_clock_skew: i64) -> bool {
+        let date = match self.header("date") {
+            Some(d) => match DateTime::parse_from_rfc2822(d) {
+                Ok(d) => d.with_timezone(&Utc),
+                Err(_) => return false,
+            },
+            None => return false,
+        };
+        (now - date).num_seconds().abs() <= max_clock_skew
+    }
+}
+
+pub fn http_digest_header(data: &str) -> String {
+    let sha256_hash = digest(data);
+    let binaries = sha256_hash.chars()
+        .collect::<Vec<char>>()
+        .chunks(2)
+        .map(|c| c.iter().collect::<String>())
+        .map(|hex| u8::from_str_radix(&hex, 16).unwrap())
+        .collect::<Vec<u8>>();
+    format!("SHA-256={}", general_purpose::STANDARD.encode(binaries))
+}
+
+#[derive(Debug)]
+pub struct SignatureHeader {
+    pub key_id: String,
+    pub algorithm: String,
+    pub headers: Vec<String>,
+    pub signature: String,
+}
+
+impl SignatureHeader {
+    // keyId="...",algorithm="rsa-sha256",headers="(request-target) host date digest",signature="..."
+    pub fn parse(value: &str) -> Result<SignatureHeader, CommonError> {
+        let mut params = HashMap::new();
+        for item in value.split(',') {
+            let (k, v) = match item.trim().split_once('=') {
+                Some(p) => p,
+                None => return Err(CommonError::new(CommonErrorCode::InvalidSignature)),
+            };
+            params.insert(k.trim().to_string(), v.trim().trim_matches('"').to_string());
+        }
+
+        let key_id = match params.remove("keyId") {
+            Some(k) => k,
+            None => return Err(CommonError::new(CommonErrorCode::InvalidSignature)),
+        };
+        let signature = match params.remove("signature") {
+            Some(s) => s,
+            None => return Err(CommonError::new(CommonErrorCode::InvalidSignature)),
+        };
+        // "date" is the default value when headers parameter is omitted
+        let headers = params.remove("headers").unwrap_or("date".to_string())
+            .split_whitespace()
+            .map(|h| h.to_lowercase())
+            .collect();
+
+        Ok(SignatureHeader {
+            key_id,
+            algorithm: params.remove("algorithm").unwrap_or("rsa-sha256".to_string()),
+            headers,
+            signature,
+        })
+    }
+
+    pub fn verify(&self, signing_string: &str, public_key_pem: &str) -> bool {
+        // "hs2019" is sent by some implementations with rsa keys
+        if self.algorithm != "rsa-sha256" && self.algorithm != "hs2019" {
+            return false;
+        }
+        let public_key = match PKey::public_key_from_pem(public_key_pem.as_bytes()) {
+            Ok(k) => k,
+            Err(_) => return false,
+        };
+        let signature = match general_purpose::STANDARD.decode(&self.signature) {
+            Ok(s) => s,
+            Err(_) => return false,
+        };
+        let mut verifier = match Verifier::new(MessageDigest::sha256(), &public_key) {
+            Ok(v) => v,
+            Err(_) => return false,
+        };
+        if verifier.set_rsa_padding(Padding::PKCS1).is_err() {
+            return false;
+        }
+        verifier.verify_oneshot(&signature, signing_string.as_bytes()).unwrap_or(false)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashMap;
+    use chrono::{Duration, TimeZone, Utc};
+    use crate::domain::activity_pub::http_signature::{http_digest_header, InboxRequest, SignatureHeader};
+    use crate::domain::user::user::User;
+
+    fn inbox_request() -> InboxRequest {
+        let mut headers = HashMap::new();
+        headers.insert("host".to_string(), "test.example.com".to_string());
+        headers.insert("date".to_string(), "Sun, 06 Nov 1994 08:49:37 GMT".to_string());
+        headers.insert("digest".to_string(), "SHA-256=abcd".to_string());
+        InboxRequest {
+            method: "POST".to_string(),
+            path: "/users/abcd1234/inbox".to_string(),
+            headers,
+            body: "{}".to_string(),
+        }
+    }
+
+    #[test]
+    fn parse_signature_header() {
+        let header = SignatureHeader::parse(
+            r#"keyId="https://remote.example.com/users/foo#main-key",algorithm="rsa-sha256",headers="(request-target) host date digest",signature="c2lnbg==""#
+        ).unwrap();
+        assert_eq!(header.key_id, "https://remote.example.com/users/foo#main-key");
+        assert_eq!(header.headers, vec!["(request-target)", "host", "date", "digest"]);
+        assert_eq!(header.signature, "c2lnbg==");
+
+        assert!(SignatureHeader::parse(r#"algorithm="rsa-sha256""#).is_err());
+        assert!(SignatureHeader::parse("").is_err());
+    }
+
+    #[test]
+    fn verify_signature() {
+        let user = User::new("hoge", "Hoge One");
+        let public_key_pem = String::from_utf8(user.key_pair.public_key.public_key_to_pem().unwrap()).unwrap();
+        let request = inbox_request();
+        let headers: Vec<String> = ["(request-target)", "host", "date", "digest"].iter().map(|h| h.to_string()).collect();
+        let signing_string = request.signing_string(&headers).unwrap();
+        assert_eq!(
+            signing_string,
+            "(request-target): post /users/abcd1234/inbox\nhost: test.example.com\ndate: Sun, 06 Nov 1994 08:49:37 GMT\ndigest: SHA-256=abcd"
+        );
+
+        let header = SignatureHeader {
+            key_id: "https://remote.example.com/users/hoge#main-key".to_string(),
+            algorithm: "rsa-sha256".to_string(),
+            headers,
+            signature: user.sign(signing_string.as_bytes()),
+        };
+        assert!(header.verify(&signing_string, &public_key_pem));
+
+        // tampered
+        assert!(!header.verify(&signing_string.replace("abcd1234", "efgh5678"), &public_key_pem));
+
+        // signed by another key
+        let other = User::new("fuga", "Fuga One");
+        let other_pem = String::from_utf8(other.key_pair.public_key.public_key_to_pem().unwrap()).unwrap();
+        assert!(!header.verify(&signing_string, &other_pem));
+    }
+
+    #[test]
+    fn missing_signed_header() {
+        let request = inbox_request();
+        let headers = vec!["(request-target)".to_string(), "content-type".to_string()];
+        assert!(request.signing_string(&headers).is_err());
+    }
+
+    #[test]
+    fn verify_digest() {
+        let mut request = inbox_request();
+        assert!(!request.verify_digest());
+
+        request.headers.insert("digest".to_string(), http_digest_header(&request.body));
+        assert!(request.verify_digest());
+
+        request.body = "{\"type\": \"Follow\"}".to_string();
+        assert!(!request.verify_digest());
+
+        request.headers.remove("digest");
+        assert!(!request.verify_digest());
+    }
+
+    #[test]
+    fn verify_date() {
+        let mut request = inbox_request();
+        let date = Utc.with_ymd_and_hms(1994, 11, 6, 8, 49, 37).unwrap();
+
+        assert!(request.verify_date(date, 300));
+        assert!(request.verify_date(date + Duration::seconds(300), 300));
+        assert!(request.verify_date(date - Duration::seconds(300), 300));
+        assert!(!request.verify_date(date + Duration::seconds(301), 300));
+        assert!(!request.verify_date(date - Duration::seconds(301), 300));
+
+        request.headers.insert("date".to_string(), "yesterday".to_string());
+        assert!(!request.verify_date(date, 300));
+
+        request.headers.remove("date");
+        assert!(!request.verify_date(date, 300));
+    }
+}
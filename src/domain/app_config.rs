@@ -4,4 +4,13 @@ pub struct AppConfig {
     pub app_url_host: String,
     pub admin_api_key: String,
     pub database_url: String,
+    pub signature_clock_skew: i64,
+    pub processed_activity_retention: i64,
+    pub delivery_max_attempts: i32,
+    pub delivery_retry_base: i64,
+    pub delivery_concurrency: usize,
+    pub delivery_host_concurrency: usize,
+    pub delivery_timeout: u64,
+    pub delivery_job_retention: i64,
+    pub instance_unreachable_threshold: i64,
 }
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+
+// remote actor blocked by local user
+#[derive(Clone, Debug)]
+pub struct Block {
+    pub id: i32,
+    pub user_id: String,
+    pub actor: String,
+    pub object: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl Block {
+    // object is the id of Block activity sent to the actor
+    pub fn new(user_id: &str, actor: &str, object: &str) -> Self {
+        Block {
+            id: 0,
+            user_id: user_id.to_string(),
+            actor: actor.to_string(),
+            object: object.to_string(),
+            created_at: Utc::now(),
+        }
+    }
+}
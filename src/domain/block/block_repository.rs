@@ -0,0 +1,13 @@
+use async_trait::async_trait;
+use crate::domain::block::block::Block;
+use crate::domain::error::CommonError;
+
+#[async_trait]
+pub trait BlockRepository: Sync + Send {
+    async fn add(&self, new_block: &Block) -> Result<Block, CommonError>;
+    // oldest first
+    async fn list(&self, user_id: &str) -> Result<Vec<Block>, CommonError>;
+    async fn get(&self, user_id: &str, block_id: i32) -> Result<Block, CommonError>;
+    async fn find(&self, user_id: &str, actor: &str) -> Result<Option<Block>, CommonError>;
+    async fn delete(&self, block_id: i32) -> Result<(), CommonError>;
+}
@@ -1,3 +1,22 @@
 pub const DATABASE_URL: &str = "DATABASE_URL";
 pub const APP_URL: &str = "APP_URL";
 pub const ADMIN_API_KEY: &str = "ADMIN_API_KEY";
+pub const SIGNATURE_CLOCK_SKEW: &str = "SIGNATURE_CLOCK_SKEW";
+pub const PROCESSED_ACTIVITY_RETENTION: &str = "PROCESSED_ACTIVITY_RETENTION";
+pub const DELIVERY_MAX_ATTEMPTS: &str = "DELIVERY_MAX_ATTEMPTS";
+pub const DELIVERY_RETRY_BASE: &str = "DELIVERY_RETRY_BASE";
+pub const DELIVERY_CONCURRENCY: &str = "DELIVERY_CONCURRENCY";
+pub const DELIVERY_HOST_CONCURRENCY: &str = "DELIVERY_HOST_CONCURRENCY";
+pub const DELIVERY_TIMEOUT: &str = "DELIVERY_TIMEOUT";
+pub const DELIVERY_JOB_RETENTION: &str = "DELIVERY_JOB_RETENTION";
+pub const INSTANCE_UNREACHABLE_THRESHOLD: &str = "INSTANCE_UNREACHABLE_THRESHOLD";
+
+pub const DEFAULT_SIGNATURE_CLOCK_SKEW: i64 = 300;
+pub const DEFAULT_PROCESSED_ACTIVITY_RETENTION: i64 = 604800;
+pub const DEFAULT_DELIVERY_MAX_ATTEMPTS: i32 = 10;
+pub const DEFAULT_DELIVERY_RETRY_BASE: i64 = 60;
+pub const DEFAULT_DELIVERY_CONCURRENCY: usize = 16;
+pub const DEFAULT_DELIVERY_HOST_CONCURRENCY: usize = 2;
+pub const DEFAULT_DELIVERY_TIMEOUT: u64 = 10;
+pub const DEFAULT_DELIVERY_JOB_RETENTION: i64 = 604800;
+pub const DEFAULT_INSTANCE_UNREACHABLE_THRESHOLD: i64 = 604800;
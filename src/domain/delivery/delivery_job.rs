@@ -0,0 +1,129 @@
+use chrono::{DateTime, Duration, Utc};
+
+// outgoing activity waiting for delivery to a remote inbox
+#[derive(Clone, Debug)]
+pub struct DeliveryJob {
+    pub id: i32,
+    pub user_id: String,
+    pub inbox: String,
+    pub body: String,
+    pub status: DeliveryJobStatus,
+    pub attempts: i32,
+    pub next_attempt_at: DateTime<Utc>,
+    pub last_status: Option<i32>,
+    pub last_error: Option<String>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum DeliveryJobStatus {
+    UNKNOWN,
+    PENDING,
+    DELIVERED,
+    FAILED,
+}
+
+impl DeliveryJob {
+    // user_id is the sender whose key signs the request
+    pub fn new(user_id: &str, inbox: &str, body: &str) -> Self {
+        let now = Utc::now();
+
+        DeliveryJob {
+            id: 0,
+            user_id: user_id.to_string(),
+            inbox: inbox.to_string(),
+            body: body.to_string(),
+            status: DeliveryJobStatus::PENDING,
+            attempts: 0,
+            next_attempt_at: now,
+            last_status: None,
+            last_error: None,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+
+    pub fn delivered(&mut self, status: i32, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.status = DeliveryJobStatus::DELIVERED;
+        self.last_status = Some(status);
+        self.last_error = None;
+        self.updated_at = now;
+    }
+
+    // held back without being counted as an attempt
+    pub fn postponed(&mut self, error: &str, until: DateTime<Utc>, now: DateTime<Utc>) {
+        self.last_error = Some(error.to_string());
+        self.next_attempt_at = until;
+        self.updated_at = now;
+    }
+
+    // retry after retry_base * 2^(attempts - 1) seconds until max_attempts is reached
+    pub fn failed(&mut self, status: Option<i32>, error: &str, retryable: bool, max_attempts: i32, retry_base: i64, now: DateTime<Utc>) {
+        self.attempts += 1;
+        self.last_status = status;
+        self.last_error = Some(error.to_string());
+        self.updated_at = now;
+
+        if !retryable || self.attempts >= max_attempts {
+            self.status = DeliveryJobStatus::FAILED;
+            return;
+        }
+        let exponent = (self.attempts - 1).min(30) as u32;
+        self.next_attempt_at = now + Duration::seconds(retry_base.saturating_mul(2_i64.pow(exponent)));
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, Utc};
+    use crate::domain::delivery::delivery_job::{DeliveryJob, DeliveryJobStatus};
+
+    #[test]
+    fn test_retry_with_backoff() {
+        let mut job = DeliveryJob::new("abcd1234", "https://remote.example.com/inbox", "{}");
+        let now = Utc::now();
+        assert_eq!(job.status, DeliveryJobStatus::PENDING);
+
+        job.failed(None, "connection refused", true, 3, 60, now);
+        assert_eq!(job.status, DeliveryJobStatus::PENDING);
+        assert_eq!(job.attempts, 1);
+        assert_eq!(job.next_attempt_at, now + Duration::seconds(60));
+
+        job.failed(Some(503), "Service Unavailable", true, 3, 60, now);
+        assert_eq!(job.status, DeliveryJobStatus::PENDING);
+        assert_eq!(job.next_attempt_at, now + Duration::seconds(120));
+        assert_eq!(job.last_status, Some(503));
+
+        job.failed(Some(503), "Service Unavailable", true, 3, 60, now);
+        assert_eq!(job.status, DeliveryJobStatus::FAILED);
+        assert_eq!(job.attempts, 3);
+    }
+
+    #[test]
+    fn test_delivered_and_permanent_failure() {
+        let mut job = DeliveryJob::new("abcd1234", "https://remote.example.com/inbox", "{}");
+        job.failed(None, "timeout", true, 3, 60, Utc::now());
+        job.delivered(202, Utc::now());
+        assert_eq!(job.status, DeliveryJobStatus::DELIVERED);
+        assert_eq!(job.last_status, Some(202));
+        assert_eq!(job.last_error, None);
+
+        let mut job = DeliveryJob::new("abcd1234", "https://remote.example.com/inbox", "{}");
+        job.failed(Some(410), "Gone", false, 3, 60, Utc::now());
+        assert_eq!(job.status, DeliveryJobStatus::FAILED);
+        assert_eq!(job.attempts, 1);
+    }
+
+    #[test]
+    fn test_postponed() {
+        let mut job = DeliveryJob::new("abcd1234", "https://remote.example.com/inbox", "{}");
+        let now = Utc::now();
+        job.postponed("Host is unreachable", now + Duration::hours(1), now);
+        assert_eq!(job.status, DeliveryJobStatus::PENDING);
+        assert_eq!(job.attempts, 0);
+        assert_eq!(job.next_attempt_at, now + Duration::hours(1));
+        assert_eq!(job.last_error, Some("Host is unreachable".to_string()));
+    }
+}
@@ -0,0 +1,16 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::delivery::delivery_job::DeliveryJob;
+use crate::domain::error::CommonError;
+
+#[async_trait]
+pub trait DeliveryJobRepository: Sync + Send {
+    async fn add(&self, new_job: &DeliveryJob) -> Result<(), CommonError>;
+    // pending jobs whose next attempt is due
+    async fn list_due(&self, now: DateTime<Utc>, limit: u64) -> Result<Vec<DeliveryJob>, CommonError>;
+    async fn update(&self, job: &DeliveryJob) -> Result<(), CommonError>;
+    // pending jobs to the host become due at now
+    async fn resume_host(&self, host: &str, now: DateTime<Utc>) -> Result<(), CommonError>;
+    // delivered or failed jobs last updated before the cutoff
+    async fn delete_finished_before(&self, cutoff: DateTime<Utc>) -> Result<(), CommonError>;
+}
@@ -0,0 +1,258 @@
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime};
+use actix_web::http::header::Date;
+use awc::Client;
+use chrono::Utc;
+use futures_util::future::join_all;
+use tokio::sync::Semaphore;
+use url::Url;
+use crate::domain::activity_pub::http_signature::http_digest_header;
+use crate::domain::app_config::AppConfig;
+use crate::domain::delivery::delivery_job::DeliveryJob;
+use crate::domain::delivery::delivery_job_repository::DeliveryJobRepository;
+use crate::domain::error::CommonError;
+use crate::domain::instance::instance_service::InstanceService;
+use crate::domain::user::user::User;
+use crate::domain::user::user_repository::UserRepository;
+
+// number of jobs processed at once
+const DELIVERY_BATCH_SIZE: u64 = 100;
+const DELIVERY_POLL_INTERVAL: Duration = Duration::from_secs(5);
+const DELIVERY_SWEEP_INTERVAL: Duration = Duration::from_secs(3600);
+
+pub struct DeliveryService {
+    app_url: String,
+    max_attempts: i32,
+    retry_base: i64,
+    concurrency: usize,
+    host_concurrency: usize,
+    timeout: Duration,
+    job_retention: i64,
+    delivery_job_repository: Arc<dyn DeliveryJobRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    instance_service: Arc<InstanceService>,
+}
+
+impl DeliveryService {
+    pub fn new(
+        app_config: Arc<AppConfig>,
+        delivery_job_repository: Arc<dyn DeliveryJobRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        instance_service: Arc<InstanceService>,
+    ) -> Self {
+        DeliveryService {
+            app_url: app_config.app_url.clone(),
+            max_attempts: app_config.delivery_max_attempts,
+            retry_base: app_config.delivery_retry_base,
+            concurrency: app_config.delivery_concurrency.max(1),
+            host_concurrency: app_config.delivery_host_concurrency.max(1),
+            timeout: Duration::from_secs(app_config.delivery_timeout),
+            job_retention: app_config.delivery_job_retention,
+            delivery_job_repository,
+            user_repository,
+            instance_service,
+        }
+    }
+
+    // store the activity to be delivered by the worker
+    pub async fn enqueue(&self, sender: &User, inbox: &str, body: &str) -> Result<(), CommonError> {
+        if Url::parse(inbox).is_err() {
+            log::warn!("Invalid inbox url: {}", inbox);
+            return Ok(());
+        }
+        self.delivery_job_repository.add(&DeliveryJob::new(&sender.id, inbox, body)).await
+    }
+
+    // awc client is not thread safe, so it is owned by the worker and shared between its deliveries
+    pub fn client(&self) -> Client {
+        Client::builder().timeout(self.timeout).finish()
+    }
+
+    // background worker, jobs are kept in database and resumed after restart
+    pub async fn run(self: Arc<Self>) {
+        let client = self.client();
+        let mut next_sweep = Instant::now();
+        loop {
+            match self.drain(&client).await {
+                Ok(n) if n > 0 => log::info!("Processed {} delivery jobs", n),
+                Ok(_) => {}
+                Err(e) => log::error!("Failed to process delivery jobs: {}", e.get_message()),
+            }
+            if Instant::now() >= next_sweep {
+                if let Err(e) = self.sweep_finished().await {
+                    log::error!("Failed to sweep delivery jobs: {}", e.get_message());
+                }
+                next_sweep = Instant::now() + DELIVERY_SWEEP_INTERVAL;
+            }
+            actix_web::rt::time::sleep(DELIVERY_POLL_INTERVAL).await;
+        }
+    }
+
+    // process batches without waiting until the queue has no more due jobs, returns the number of processed jobs
+    pub async fn drain(&self, client: &Client) -> Result<usize, CommonError> {
+        let mut total = 0;
+        loop {
+            let n = self.deliver_due(client).await?;
+            total += n;
+            // a full batch means more jobs may be due
+            if n < DELIVERY_BATCH_SIZE as usize {
+                return Ok(total);
+            }
+        }
+    }
+
+    // finished jobs keep the signed body, they are only useful for a while to look into failures
+    pub async fn sweep_finished(&self) -> Result<(), CommonError> {
+        let cutoff = Utc::now() - chrono::Duration::seconds(self.job_retention);
+        self.delivery_job_repository.delete_finished_before(cutoff).await
+    }
+
+    // returns the number of processed jobs
+    pub async fn deliver_due(&self, client: &Client) -> Result<usize, CommonError> {
+        let jobs = self.delivery_job_repository.list_due(Utc::now(), DELIVERY_BATCH_SIZE).await?;
+        let count = jobs.len();
+
+        // a slow server must not occupy all connections
+        let total = Semaphore::new(self.concurrency);
+        let mut hosts: HashMap<String, Semaphore> = HashMap::new();
+        for job in jobs.iter() {
+            hosts.entry(host_of(&job.inbox)).or_insert(Semaphore::new(self.host_concurrency));
+        }
+
+        let results = join_all(jobs.into_iter().map(|job| {
+            let (total, host) = (&total, &hosts[&host_of(&job.inbox)]);
+            async move {
+                let _host_permit = host.acquire().await;
+                let _permit = total.acquire().await;
+                self.deliver(client, job).await
+            }
+        })).await;
+
+        for r in results.into_iter() {
+            r?;
+        }
+        Ok(count)
+    }
+
+    async fn deliver(&self, client: &Client, mut job: DeliveryJob) -> Result<(), CommonError> {
+        let sender = match self.user_repository.get(&job.user_id).await {
+            Ok(u) => u,
+            Err(e) => {
+                job.failed(None, &e.get_message(), false, self.max_attempts, self.retry_base, Utc::now());
+                return self.delivery_job_repository.update(&job).await;
+            }
+        };
+
+        // do not keep hammering dead hosts, the job waits for the next probe or the host to be back
+        if let Some(until) = self.instance_service.suspended_until(&job.inbox).await? {
+            job.postponed("Host is unreachable", until, Utc::now());
+            return self.delivery_job_repository.update(&job).await;
+        }
+
+        match self.post(client, &sender, &job.inbox, &job.body).await {
+            Ok(status) => {
+                self.record_health(&job.inbox, Some(status)).await?;
+                job.delivered(status, Utc::now());
+            }
+            Err((status, error, retryable)) => {
+                log::warn!("Failed to deliver to {} (attempt {}): {}", job.inbox, job.attempts + 1, error);
+                self.record_health(&job.inbox, status).await?;
+                job.failed(status, &error, retryable, self.max_attempts, self.retry_base, Utc::now());
+            }
+        }
+        self.delivery_job_repository.update(&job).await
+    }
+
+    // without queueing, for activities that can not be signed later (e.g. the sender is being deleted)
+    // failures are not retried, returns the number of delivered inboxes
+    pub async fn deliver_now(&self, sender: &User, inboxes: &[String], body: &str) -> usize {
+        let client = self.client();
+        let total = Semaphore::new(self.concurrency);
+
+        let results = join_all(inboxes.iter().map(|inbox| {
+            let (client, total) = (&client, &total);
+            async move {
+                let _permit = total.acquire().await;
+                if !self.instance_service.is_deliverable(inbox).await.unwrap_or(false) {
+                    return false;
+                }
+                let (status, delivered) = match self.post(client, sender, inbox, body).await {
+                    Ok(s) => (Some(s), true),
+                    Err((s, error, _)) => {
+                        log::warn!("Failed to deliver to {}: {}", inbox, error);
+                        (s, false)
+                    }
+                };
+                if let Err(e) = self.record_health(inbox, status).await {
+                    log::error!("Failed to record instance health: {}", e.get_message());
+                }
+                delivered
+            }
+        })).await;
+
+        results.into_iter().filter(|d| *d).count()
+    }
+
+    // any response except server errors shows the host is alive
+    async fn record_health(&self, inbox: &str, status: Option<i32>) -> Result<(), CommonError> {
+        match status {
+            Some(s) if s < 500 => self.instance_service.record_success(inbox).await,
+            _ => self.instance_service.record_failure(inbox).await,
+        }
+    }
+
+    // sign and send, returns (status, error, retryable) on failure
+    async fn post(&self, client: &Client, sender: &User, inbox: &str, body: &str) -> Result<i32, (Option<i32>, String, bool)> {
+        let parsed_url = match Url::parse(inbox) {
+            Ok(u) => u,
+            Err(e) => return Err((None, e.to_string(), false)),
+        };
+        let host = match parsed_url.port() {
+            Some(port) => format!("{}:{}", parsed_url.host_str().unwrap_or_default(), port),
+            None => parsed_url.host_str().unwrap_or_default().to_string(),
+        };
+
+        // http signature is created at send time since Date must be fresh
+        let now = Date(SystemTime::now().into());
+        let digest_header = http_digest_header(body);
+        let signature_data = format!(
+            "(request-target): post {}\nhost: {}\ndate: {}\ndigest: {}",
+            parsed_url.path(), host, now, digest_header
+        );
+        let signature = sender.sign(signature_data.as_bytes());
+
+        let req = client.post(inbox)
+            .insert_header(("Host", host))
+            .insert_header(now)
+            .insert_header(("Digest", digest_header))
+            .insert_header(("Content-Type", "application/activity+json; charset=utf-8"))
+            .insert_header((
+                "Signature",
+                format!(
+                    "keyId=\"{}users/{}#main-key\",algorithm=\"rsa-sha256\",headers=\"(request-target) host date digest\",signature=\"{}\"",
+                    self.app_url, sender.id, signature
+                )
+            ));
+        let res = match req.send_body(body.to_string()).await {
+            Ok(r) => r,
+            Err(e) => return Err((None, e.to_string(), true)),
+        };
+
+        let status = res.status();
+        if status.is_success() {
+            return Ok(status.as_u16() as i32);
+        }
+        // client errors will not be fixed by retrying, except for timeout and rate limit
+        let retryable = !status.is_client_error() || status.as_u16() == 408 || status.as_u16() == 429;
+        Err((Some(status.as_u16() as i32), status.to_string(), retryable))
+    }
+}
+
+// deliveries are limited per host and port
+fn host_of(inbox: &str) -> String {
+    match Url::parse(inbox) {
+        Ok(u) => format!("{}:{}", u.host_str().unwrap_or_default(), u.port_or_known_default().unwrap_or_default()),
+        Err(_) => inbox.to_string(),
+    }
+}
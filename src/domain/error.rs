@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::sync::Mutex;
 use once_cell::sync::Lazy;
-use crate::domain::error::CommonErrorCode::{DBError, NoteDoesNotExists, UnexpectedError, UserDoesNotExists, UsernameAlreadyExists};
+use crate::domain::error::CommonErrorCode::{AlreadyBlocking, AlreadyFollowing, BlockDoesNotExists, DBError, FollowRequestDoesNotExists, FollowerDoesNotExists, FollowingDoesNotExists, InvalidActivity, InvalidSignature, NoteDoesNotExists, NoteNotPinnable, RemoteActorDoesNotExists, TooManyProfileFields, UnexpectedError, UserDoesNotExists, UserGone, UsernameAlreadyExists};
 
 #[derive(Debug)]
 pub struct CommonError {
@@ -32,8 +32,20 @@ impl CommonError {
 #[derive(Clone, Debug, Eq, Hash, PartialEq)]
 pub enum CommonErrorCode {
     UserDoesNotExists,
+    UserGone,
     UsernameAlreadyExists,
+    TooManyProfileFields,
     NoteDoesNotExists,
+    NoteNotPinnable,
+    RemoteActorDoesNotExists,
+    FollowerDoesNotExists,
+    FollowingDoesNotExists,
+    FollowRequestDoesNotExists,
+    BlockDoesNotExists,
+    AlreadyFollowing,
+    AlreadyBlocking,
+    InvalidSignature,
+    InvalidActivity,
     DBError,
     UnexpectedError,
 }
@@ -42,8 +54,20 @@ static COMMON_ERROR_MESSAGES: Lazy<Mutex<HashMap<CommonErrorCode, String>>> = La
     let mut m = HashMap::new();
 
     m.insert(UserDoesNotExists, "User does not exists".to_string());
+    m.insert(UserGone, "User has been deleted".to_string());
     m.insert(UsernameAlreadyExists, "Username already exists".to_string());
+    m.insert(TooManyProfileFields, "Too many profile fields".to_string());
     m.insert(NoteDoesNotExists, "Note does not exists".to_string());
+    m.insert(NoteNotPinnable, "Followers only note can not be pinned".to_string());
+    m.insert(RemoteActorDoesNotExists, "Remote actor does not exists".to_string());
+    m.insert(FollowerDoesNotExists, "Follower does not exists".to_string());
+    m.insert(FollowingDoesNotExists, "Following does not exists".to_string());
+    m.insert(FollowRequestDoesNotExists, "Follow request does not exists".to_string());
+    m.insert(BlockDoesNotExists, "Block does not exists".to_string());
+    m.insert(AlreadyFollowing, "Already following".to_string());
+    m.insert(AlreadyBlocking, "Already blocking".to_string());
+    m.insert(InvalidSignature, "Invalid signature".to_string());
+    m.insert(InvalidActivity, "Invalid activity".to_string());
     m.insert(DBError, "DB error".to_string());
     m.insert(UnexpectedError, "Unexpected error".to_string());
 
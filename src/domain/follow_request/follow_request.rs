@@ -0,0 +1,52 @@
+use chrono::{DateTime, Utc};
+use crate::domain::follower::follower::Follower;
+
+// pending Follow to a locked user
+#[derive(Clone, Debug)]
+pub struct FollowRequest {
+    pub id: i32,
+    pub user_id: String,
+    pub actor: String,
+    pub object: String,
+    pub inbox: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl FollowRequest {
+    // object is the id of Follow activity received from the actor
+    pub fn new(user_id: &str, actor: &str, object: &str, inbox: &str) -> Self {
+        FollowRequest {
+            id: 0,
+            user_id: user_id.to_string(),
+            actor: actor.to_string(),
+            object: object.to_string(),
+            inbox: inbox.to_string(),
+            created_at: Utc::now(),
+        }
+    }
+
+    pub fn to_follower(&self) -> Follower {
+        Follower::new(&self.user_id, &self.actor, &self.object, &self.inbox)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::follow_request::follow_request::FollowRequest;
+
+    #[test]
+    fn test_to_follower() {
+        let request = FollowRequest::new(
+            "abcd1234",
+            "https://remote.example.com/users/foo",
+            "https://remote.example.com/follows/1",
+            "https://remote.example.com/users/foo/inbox",
+        );
+        let follower = request.to_follower();
+
+        assert_eq!(follower.user_id, "abcd1234");
+        assert_eq!(follower.actor, "https://remote.example.com/users/foo");
+        assert_eq!(follower.object, "https://remote.example.com/follows/1");
+        assert_eq!(follower.inbox, "https://remote.example.com/users/foo/inbox");
+    }
+}
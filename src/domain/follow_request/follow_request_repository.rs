@@ -0,0 +1,14 @@
+use async_trait::async_trait;
+use crate::domain::error::CommonError;
+use crate::domain::follow_request::follow_request::FollowRequest;
+
+#[async_trait]
+pub trait FollowRequestRepository: Sync + Send {
+    async fn add(&self, new_request: &FollowRequest) -> Result<(), CommonError>;
+    // oldest first
+    async fn list(&self, user_id: &str) -> Result<Vec<FollowRequest>, CommonError>;
+    async fn get(&self, user_id: &str, request_id: i32) -> Result<FollowRequest, CommonError>;
+    async fn find(&self, user_id: &str, actor: &str) -> Result<Option<FollowRequest>, CommonError>;
+    async fn update(&self, request: &FollowRequest) -> Result<(), CommonError>;
+    async fn delete(&self, request_id: i32) -> Result<(), CommonError>;
+}
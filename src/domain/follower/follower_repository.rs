@@ -6,5 +6,9 @@ use crate::domain::follower::follower::Follower;
 pub trait FollowerRepository: Sync + Send {
     async fn add(&self, new_follower: &Follower) -> Result<(), CommonError>;
     async fn list(&self, user_id: &String) -> Result<Vec<Follower>, CommonError>;
+    async fn list_by_actor(&self, actor: &str) -> Result<Vec<Follower>, CommonError>;
+    async fn find(&self, user_id: &str, actor: &str) -> Result<Option<Follower>, CommonError>;
+    async fn find_by_object(&self, user_id: &str, object: &str) -> Result<Option<Follower>, CommonError>;
+    async fn update(&self, follower: &Follower) -> Result<(), CommonError>;
     async fn delete(&self, follower_id: i32) -> Result<(), CommonError>;
 }
@@ -0,0 +1,56 @@
+use chrono::{DateTime, Utc};
+
+// remote account followed by local user
+#[derive(Clone, Debug)]
+pub struct Following {
+    pub id: i32,
+    pub user_id: String,
+    pub actor: String,
+    pub object: String,
+    pub status: FollowingStatus,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum FollowingStatus {
+    UNKNOWN,
+    PENDING,
+    ACCEPTED,
+    REJECTED,
+}
+
+impl Following {
+    // object is the id of Follow activity sent to the actor
+    pub fn new(user_id: &str, actor: &str, object: &str) -> Self {
+        let now = Utc::now();
+
+        Following {
+            id: 0,
+            user_id: user_id.to_string(),
+            actor: actor.to_string(),
+            object: object.to_string(),
+            status: FollowingStatus::PENDING,
+            created_at: now,
+            updated_at: now,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::following::following::{Following, FollowingStatus};
+
+    #[test]
+    fn test_new_following() {
+        let following = Following::new(
+            "abcd1234",
+            "https://remote.example.com/users/foo",
+            "https://test.example.com/activities/efgh5678",
+        );
+
+        assert_eq!(following.user_id, "abcd1234");
+        assert_eq!(following.actor, "https://remote.example.com/users/foo");
+        assert_eq!(following.status, FollowingStatus::PENDING);
+    }
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use crate::domain::error::CommonError;
+use crate::domain::following::following::Following;
+
+#[async_trait]
+pub trait FollowingRepository: Sync + Send {
+    async fn add(&self, new_following: &Following) -> Result<Following, CommonError>;
+    async fn list(&self, user_id: &str) -> Result<Vec<Following>, CommonError>;
+    async fn get(&self, user_id: &str, following_id: i32) -> Result<Following, CommonError>;
+    async fn find(&self, user_id: &str, actor: &str) -> Result<Option<Following>, CommonError>;
+    async fn find_by_object(&self, object: &str) -> Result<Option<Following>, CommonError>;
+    async fn list_by_actor(&self, actor: &str) -> Result<Vec<Following>, CommonError>;
+    async fn update(&self, following: &Following) -> Result<(), CommonError>;
+    async fn delete(&self, following_id: i32) -> Result<(), CommonError>;
+}
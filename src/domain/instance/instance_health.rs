@@ -0,0 +1,116 @@
+use chrono::{DateTime, Duration, Utc};
+use url::Url;
+
+// while suspended, a delivery is tried once in this period to check the host is back
+const PROBE_INTERVAL_SECONDS: i64 = 3600;
+
+// delivery health of a remote domain
+#[derive(Clone, Debug)]
+pub struct InstanceHealth {
+    pub host: String,
+    pub consecutive_failures: i32,
+    pub last_success_at: Option<DateTime<Utc>>,
+    pub last_failure_at: Option<DateTime<Utc>>,
+    pub unreachable_since: Option<DateTime<Utc>>,
+    pub updated_at: DateTime<Utc>,
+}
+
+impl InstanceHealth {
+    pub fn new(host: &str) -> Self {
+        InstanceHealth {
+            host: host.to_string(),
+            consecutive_failures: 0,
+            last_success_at: None,
+            last_failure_at: None,
+            unreachable_since: None,
+            updated_at: Utc::now(),
+        }
+    }
+
+    pub fn succeeded(&mut self, now: DateTime<Utc>) {
+        self.consecutive_failures = 0;
+        self.last_success_at = Some(now);
+        self.unreachable_since = None;
+        self.updated_at = now;
+    }
+
+    pub fn failed(&mut self, now: DateTime<Utc>) {
+        self.consecutive_failures += 1;
+        self.last_failure_at = Some(now);
+        self.unreachable_since.get_or_insert(now);
+        self.updated_at = now;
+    }
+
+    // unreachable longer than threshold seconds
+    pub fn is_suspended(&self, now: DateTime<Utc>, threshold: i64) -> bool {
+        match self.unreachable_since {
+            Some(since) => now - since > Duration::seconds(threshold),
+            None => false,
+        }
+    }
+
+    pub fn is_deliverable(&self, now: DateTime<Utc>, threshold: i64) -> bool {
+        if !self.is_suspended(now, threshold) {
+            return true;
+        }
+        match self.last_failure_at {
+            Some(last) => now - last > Duration::seconds(PROBE_INTERVAL_SECONDS),
+            None => true,
+        }
+    }
+
+    // next probe while deliveries are held back, None if deliverable
+    pub fn suspended_until(&self, now: DateTime<Utc>, threshold: i64) -> Option<DateTime<Utc>> {
+        if self.is_deliverable(now, threshold) {
+            return None;
+        }
+        self.last_failure_at.map(|last| last + Duration::seconds(PROBE_INTERVAL_SECONDS))
+    }
+}
+
+// https://remote.example.com/inbox -> remote.example.com
+pub fn instance_host(url: &str) -> Option<String> {
+    Url::parse(url).ok()?.host_str().map(|h| h.to_lowercase())
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, Utc};
+    use crate::domain::instance::instance_health::{instance_host, InstanceHealth};
+
+    #[test]
+    fn test_suspend_and_resume() {
+        let now = Utc::now();
+        let mut health = InstanceHealth::new("remote.example.com");
+        assert!(!health.is_suspended(now, 3600));
+
+        health.failed(now - Duration::hours(3));
+        health.failed(now - Duration::hours(2));
+        assert_eq!(health.consecutive_failures, 2);
+        assert_eq!(health.unreachable_since, Some(now - Duration::hours(3)));
+        assert!(health.is_suspended(now, 3600));
+        assert!(!health.is_suspended(now, 86400));
+
+        // probe once an hour while suspended
+        assert!(health.is_deliverable(now, 3600));
+        health.failed(now);
+        assert!(!health.is_deliverable(now, 3600));
+        assert!(health.is_deliverable(now + Duration::minutes(61), 3600));
+        assert_eq!(health.suspended_until(now, 3600), Some(now + Duration::hours(1)));
+        assert_eq!(health.suspended_until(now + Duration::minutes(61), 3600), None);
+
+        health.succeeded(now);
+        assert_eq!(health.consecutive_failures, 0);
+        assert_eq!(health.unreachable_since, None);
+        assert!(!health.is_suspended(now, 3600));
+        assert!(health.is_deliverable(now, 3600));
+        assert_eq!(health.suspended_until(now, 3600), None);
+    }
+
+    #[test]
+    fn test_instance_host() {
+        assert_eq!(instance_host("https://Remote.Example.com/users/foo/inbox"), Some("remote.example.com".to_string()));
+        assert_eq!(instance_host("http://127.0.0.1:1/inbox"), Some("127.0.0.1".to_string()));
+        assert_eq!(instance_host("foo"), None);
+    }
+}
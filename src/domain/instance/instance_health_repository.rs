@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::domain::error::CommonError;
+use crate::domain::instance::instance_health::InstanceHealth;
+
+#[async_trait]
+pub trait InstanceHealthRepository: Sync + Send {
+    async fn find(&self, host: &str) -> Result<Option<InstanceHealth>, CommonError>;
+    async fn list(&self) -> Result<Vec<InstanceHealth>, CommonError>;
+    // insert or update
+    async fn save(&self, health: &InstanceHealth) -> Result<(), CommonError>;
+}
@@ -0,0 +1,90 @@
+use std::sync::Arc;
+use chrono::{DateTime, Utc};
+use crate::domain::delivery::delivery_job_repository::DeliveryJobRepository;
+use crate::domain::error::CommonError;
+use crate::domain::instance::instance_health::{instance_host, InstanceHealth};
+use crate::domain::instance::instance_health_repository::InstanceHealthRepository;
+
+pub struct InstanceService {
+    // seconds until an unreachable host is suspended
+    unreachable_threshold: i64,
+    instance_health_repository: Arc<dyn InstanceHealthRepository>,
+    delivery_job_repository: Arc<dyn DeliveryJobRepository>,
+}
+
+impl InstanceService {
+    pub fn new(
+        unreachable_threshold: i64,
+        instance_health_repository: Arc<dyn InstanceHealthRepository>,
+        delivery_job_repository: Arc<dyn DeliveryJobRepository>,
+    ) -> Self {
+        InstanceService {
+            unreachable_threshold,
+            instance_health_repository,
+            delivery_job_repository,
+        }
+    }
+
+    pub fn is_suspended(&self, health: &InstanceHealth) -> bool {
+        health.is_suspended(Utc::now(), self.unreachable_threshold)
+    }
+
+    // false while the host of url is suspended, except for periodic probes
+    pub async fn is_deliverable(&self, url: &str) -> Result<bool, CommonError> {
+        let host = match instance_host(url) {
+            Some(h) => h,
+            None => return Ok(true),
+        };
+        match self.instance_health_repository.find(&host).await? {
+            Some(h) => Ok(h.is_deliverable(Utc::now(), self.unreachable_threshold)),
+            None => Ok(true),
+        }
+    }
+
+    // time of the next probe while the host of url is suspended
+    pub async fn suspended_until(&self, url: &str) -> Result<Option<DateTime<Utc>>, CommonError> {
+        let host = match instance_host(url) {
+            Some(h) => h,
+            None => return Ok(None),
+        };
+        match self.instance_health_repository.find(&host).await? {
+            Some(h) => Ok(h.suspended_until(Utc::now(), self.unreachable_threshold)),
+            None => Ok(None),
+        }
+    }
+
+    // the host responded or sent an activity
+    pub async fn record_success(&self, url: &str) -> Result<(), CommonError> {
+        let host = match instance_host(url) {
+            Some(h) => h,
+            None => return Ok(()),
+        };
+        let mut health = self.instance_health_repository.find(&host).await?
+            .unwrap_or(InstanceHealth::new(&host));
+        let now = Utc::now();
+        let resumed = health.unreachable_since.is_some();
+        health.succeeded(now);
+        self.instance_health_repository.save(&health).await?;
+
+        // deliveries held back while unreachable are sent without waiting for the next probe
+        if resumed {
+            self.delivery_job_repository.resume_host(&host, now).await?;
+        }
+        Ok(())
+    }
+
+    pub async fn record_failure(&self, url: &str) -> Result<(), CommonError> {
+        let host = match instance_host(url) {
+            Some(h) => h,
+            None => return Ok(()),
+        };
+        let mut health = self.instance_health_repository.find(&host).await?
+            .unwrap_or(InstanceHealth::new(&host));
+        health.failed(Utc::now());
+        self.instance_health_repository.save(&health).await
+    }
+
+    pub async fn list(&self) -> Result<Vec<InstanceHealth>, CommonError> {
+        self.instance_health_repository.list().await
+    }
+}
@@ -7,6 +7,9 @@ pub struct Note {
     pub user_id: String,
     pub content: String,
     pub status: NoteStatus,
+    pub visibility: NoteVisibility,
+    // featured on the profile
+    pub pinned_at: Option<DateTime<Utc>>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
 }
@@ -18,8 +21,26 @@ pub enum NoteStatus {
     DELETED,
 }
 
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum NoteVisibility {
+    UNKNOWN,
+    // listed on public timelines
+    PUBLIC,
+    // anyone with the link
+    UNLISTED,
+    // followers only
+    FOLLOWERS,
+}
+
+impl NoteVisibility {
+    // readable without being a follower
+    pub fn is_public(&self) -> bool {
+        matches!(self, NoteVisibility::PUBLIC | NoteVisibility::UNLISTED)
+    }
+}
+
 impl Note {
-    pub fn new(user_id: &String, content: &String) -> Note {
+    pub fn new(user_id: &String, content: &String, visibility: NoteVisibility) -> Note {
         let id = IDGenerator::generate(12);
         let now = Utc::now();
 
@@ -28,22 +49,66 @@ impl Note {
             user_id: user_id.clone(),
             content: content.clone(),
             status: NoteStatus::PUBLISHED,
+            visibility,
+            pinned_at: None,
             created_at: now.clone(),
             updated_at: now.clone(),
         }
     }
+
+    pub fn edit(&mut self, content: &str, now: DateTime<Utc>) {
+        self.content = content.to_string();
+        self.updated_at = now;
+    }
+
+    pub fn pin(&mut self, now: DateTime<Utc>) {
+        self.pinned_at = Some(now);
+    }
+
+    pub fn unpin(&mut self) {
+        self.pinned_at = None;
+    }
 }
 
 #[cfg(test)]
 mod test {
-    use crate::domain::note::note::{Note, NoteStatus};
+    use chrono::Utc;
+    use crate::domain::note::note::{Note, NoteStatus, NoteVisibility};
+    use crate::domain::note::note_history::NoteHistory;
 
     #[test]
     fn test_new_note() {
-        let note = Note::new(&"abcd1234".to_string(), &"Hello, world!".to_string());
+        let note = Note::new(&"abcd1234".to_string(), &"Hello, world!".to_string(), NoteVisibility::PUBLIC);
 
         assert_ne!(note.id, "");
         assert_eq!(note.user_id, "abcd1234");
         assert_eq!(note.status, NoteStatus::PUBLISHED);
+        assert!(note.visibility.is_public());
+        assert!(note.pinned_at.is_none());
+    }
+
+    #[test]
+    fn test_edit_note() {
+        let mut note = Note::new(&"abcd1234".to_string(), &"Hello, world!".to_string(), NoteVisibility::PUBLIC);
+        let history = NoteHistory::new(&note);
+        let now = Utc::now();
+        note.edit("Hello, world!!", now);
+
+        assert_eq!(note.content, "Hello, world!!");
+        assert_eq!(note.updated_at, now);
+        assert_eq!(history.note_id, note.id);
+        assert_eq!(history.content, "Hello, world!");
+        assert_eq!(history.created_at, note.created_at);
+    }
+
+    #[test]
+    fn test_pin_note() {
+        let mut note = Note::new(&"abcd1234".to_string(), &"Hello, world!".to_string(), NoteVisibility::PUBLIC);
+        let now = Utc::now();
+        note.pin(now);
+        assert_eq!(note.pinned_at, Some(now));
+
+        note.unpin();
+        assert!(note.pinned_at.is_none());
     }
 }
@@ -0,0 +1,23 @@
+use chrono::{DateTime, Utc};
+use crate::domain::note::note::Note;
+
+// previous revision of an edited note
+#[derive(Clone, Debug)]
+pub struct NoteHistory {
+    pub id: i32,
+    pub note_id: String,
+    pub content: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl NoteHistory {
+    // keeps the content as of the last update of the note
+    pub fn new(note: &Note) -> Self {
+        NoteHistory {
+            id: 0,
+            note_id: note.id.clone(),
+            content: note.content.clone(),
+            created_at: note.updated_at,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::domain::error::CommonError;
+use crate::domain::note::note_history::NoteHistory;
+
+#[async_trait]
+pub trait NoteHistoryRepository: Sync + Send {
+    async fn add(&self, history: &NoteHistory) -> Result<(), CommonError>;
+    // oldest first
+    async fn list(&self, note_id: &str) -> Result<Vec<NoteHistory>, CommonError>;
+}
@@ -0,0 +1,11 @@
+use async_trait::async_trait;
+use crate::domain::error::CommonError;
+
+// inboxes the note has been delivered to
+#[async_trait]
+pub trait NoteRecipientRepository: Sync + Send {
+    // already recorded inboxes are skipped
+    async fn add(&self, note_id: &str, inboxes: &[String]) -> Result<(), CommonError>;
+    async fn list(&self, note_id: &str) -> Result<Vec<String>, CommonError>;
+    async fn delete(&self, note_id: &str) -> Result<(), CommonError>;
+}
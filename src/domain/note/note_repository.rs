@@ -7,6 +7,13 @@ use crate::domain::note::paging::{NotesPage, NotesPagingParams};
 pub trait NoteRepository: Sync + Send {
     async fn add(&self, new_note: &Note) -> Result<(), CommonError>;
     async fn list(&self, user_id: &String, paging_params: &NotesPagingParams) -> Result<NotesPage, CommonError>;
+    // readable without being a follower
+    async fn list_public(&self, user_id: &str, paging_params: &NotesPagingParams) -> Result<NotesPage, CommonError>;
+    async fn count_public(&self, user_id: &str) -> Result<u64, CommonError>;
+    // newest pin first
+    async fn list_pinned(&self, user_id: &str) -> Result<Vec<Note>, CommonError>;
     async fn get(&self, user_id: &String, note_id: &String) -> Result<Note, CommonError>;
+    // including deleted notes
+    async fn find(&self, note_id: &str) -> Result<Option<Note>, CommonError>;
     async fn update(&self, note: &Note) -> Result<(), CommonError>;
 }
@@ -0,0 +1,19 @@
+use chrono::{DateTime, Utc};
+
+// inbound activity already processed for the user
+#[derive(Clone, Debug)]
+pub struct ProcessedActivity {
+    pub activity_id: String,
+    pub user_id: String,
+    pub created_at: DateTime<Utc>,
+}
+
+impl ProcessedActivity {
+    pub fn new(activity_id: &str, user_id: &str) -> Self {
+        ProcessedActivity {
+            activity_id: activity_id.to_string(),
+            user_id: user_id.to_string(),
+            created_at: Utc::now(),
+        }
+    }
+}
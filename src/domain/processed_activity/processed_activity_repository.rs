@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use crate::domain::error::CommonError;
+use crate::domain::processed_activity::processed_activity::ProcessedActivity;
+
+#[async_trait]
+pub trait ProcessedActivityRepository: Sync + Send {
+    // returns false if the activity is already recorded
+    async fn add(&self, processed: &ProcessedActivity) -> Result<bool, CommonError>;
+    async fn delete(&self, activity_id: &str, user_id: &str) -> Result<(), CommonError>;
+    async fn delete_before(&self, before: DateTime<Utc>) -> Result<(), CommonError>;
+}
@@ -0,0 +1,70 @@
+use chrono::{DateTime, Duration, Utc};
+use crate::domain::activity_pub::activity_pub::RemoteActorDocument;
+
+// refetch actor document after this period
+const CACHE_TTL_HOURS: i64 = 24;
+
+#[derive(Clone, Debug)]
+pub struct RemoteActor {
+    pub id: String,
+    pub preferred_username: String,
+    pub inbox: String,
+    pub shared_inbox: Option<String>,
+    pub public_key_id: String,
+    pub public_key_pem: String,
+    pub fetched_at: DateTime<Utc>,
+}
+
+impl RemoteActor {
+    pub fn is_expired(&self, now: DateTime<Utc>) -> bool {
+        now - self.fetched_at > Duration::hours(CACHE_TTL_HOURS)
+    }
+}
+
+impl From<RemoteActorDocument> for RemoteActor {
+    fn from(value: RemoteActorDocument) -> Self {
+        RemoteActor {
+            id: value.id,
+            preferred_username: value.preferred_username.unwrap_or_default(),
+            inbox: value.inbox,
+            shared_inbox: value.endpoints.and_then(|e| e.shared_inbox),
+            public_key_id: value.public_key.id,
+            public_key_pem: value.public_key.public_key_pem,
+            fetched_at: Utc::now(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::{Duration, Utc};
+    use crate::domain::activity_pub::activity_pub::RemoteActorDocument;
+    use crate::domain::remote_actor::remote_actor::RemoteActor;
+
+    #[test]
+    fn from_actor_document() {
+        let document: RemoteActorDocument = serde_json::from_str(r#"{
+            "@context": ["https://www.w3.org/ns/activitystreams", "https://w3id.org/security/v1"],
+            "id": "https://remote.example.com/users/foo",
+            "type": "Person",
+            "preferredUsername": "foo",
+            "inbox": "https://remote.example.com/users/foo/inbox",
+            "endpoints": {"sharedInbox": "https://remote.example.com/inbox"},
+            "publicKey": {
+                "id": "https://remote.example.com/users/foo#main-key",
+                "owner": "https://remote.example.com/users/foo",
+                "publicKeyPem": "-----BEGIN PUBLIC KEY-----"
+            }
+        }"#).unwrap();
+        let actor = RemoteActor::from(document);
+
+        assert_eq!(actor.id, "https://remote.example.com/users/foo");
+        assert_eq!(actor.preferred_username, "foo");
+        assert_eq!(actor.inbox, "https://remote.example.com/users/foo/inbox");
+        assert_eq!(actor.shared_inbox, Some("https://remote.example.com/inbox".to_string()));
+        assert_eq!(actor.public_key_id, "https://remote.example.com/users/foo#main-key");
+
+        assert!(!actor.is_expired(Utc::now()));
+        assert!(actor.is_expired(Utc::now() + Duration::days(2)));
+    }
+}
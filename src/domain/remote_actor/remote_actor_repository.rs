@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::domain::error::CommonError;
+use crate::domain::remote_actor::remote_actor::RemoteActor;
+
+#[async_trait]
+pub trait RemoteActorRepository: Sync + Send {
+    async fn find(&self, actor_id: &str) -> Result<Option<RemoteActor>, CommonError>;
+    async fn find_by_key_id(&self, key_id: &str) -> Result<Option<RemoteActor>, CommonError>;
+    async fn save(&self, actor: &RemoteActor) -> Result<(), CommonError>;
+}
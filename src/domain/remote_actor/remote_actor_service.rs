@@ -0,0 +1,194 @@
+use std::sync::Arc;
+use awc::Client;
+use chrono::Utc;
+use serde::de::DeserializeOwned;
+use url::Url;
+use crate::domain::activity_pub::activity_pub::{RemoteActorDocument, RemoteKeyDocument, RemoteWebFinger};
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::remote_actor::remote_actor::RemoteActor;
+use crate::domain::remote_actor::remote_actor_repository::RemoteActorRepository;
+
+const ACTIVITY_JSON: &str = "application/activity+json";
+
+pub struct RemoteActorService {
+    remote_actor_repository: Arc<dyn RemoteActorRepository>,
+}
+
+impl RemoteActorService {
+    pub fn new(remote_actor_repository: Arc<dyn RemoteActorRepository>) -> Self {
+        RemoteActorService {
+            remote_actor_repository,
+        }
+    }
+
+    // returns cached actor if it is fresh enough
+    pub async fn resolve(&self, actor_id: &str) -> Result<RemoteActor, CommonError> {
+        if let Some(a) = self.remote_actor_repository.find(actor_id).await? {
+            if !a.is_expired(Utc::now()) {
+                return Ok(a);
+            }
+        }
+        self.refresh(actor_id).await
+    }
+
+    // cached actor regardless of its age, never fetches
+    pub async fn find_cached(&self, actor_id: &str) -> Result<Option<RemoteActor>, CommonError> {
+        self.remote_actor_repository.find(actor_id).await
+    }
+
+    // resolve "user@remote.example.com" through WebFinger
+    pub async fn resolve_account(&self, account: &str) -> Result<RemoteActor, CommonError> {
+        let (username, host) = match parse_account(account) {
+            Some(a) => a,
+            None => return Err(CommonError::new(CommonErrorCode::RemoteActorDoesNotExists)),
+        };
+
+        let url = format!("https://{}/.well-known/webfinger?resource=acct:{}@{}", host, username, host);
+        let web_finger = match self.fetch_object::<RemoteWebFinger>(&url, "application/jrd+json").await {
+            Ok(w) => w,
+            Err(_) => return Err(CommonError::new(CommonErrorCode::RemoteActorDoesNotExists)),
+        };
+        let actor_id = match web_finger.self_link() {
+            Some(l) => l,
+            None => return Err(CommonError::new(CommonErrorCode::RemoteActorDoesNotExists)),
+        };
+
+        match self.resolve(actor_id).await {
+            Ok(a) => Ok(a),
+            Err(_) => Err(CommonError::new(CommonErrorCode::RemoteActorDoesNotExists)),
+        }
+    }
+
+    pub async fn refresh(&self, actor_id: &str) -> Result<RemoteActor, CommonError> {
+        let document = self.fetch_object::<RemoteActorDocument>(actor_id, ACTIVITY_JSON).await?;
+        if !is_same_host(&document.id, actor_id) {
+            log::warn!("Actor id {} does not match the host of {}", document.id, actor_id);
+            return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+        }
+
+        let actor = RemoteActor::from(document);
+        self.remote_actor_repository.save(&actor).await?;
+        Ok(actor)
+    }
+
+    // resolve the owner of the key used for http signature
+    pub async fn resolve_by_key_id(&self, key_id: &str, force_refresh: bool) -> Result<RemoteActor, CommonError> {
+        if !force_refresh {
+            if let Some(a) = self.remote_actor_repository.find_by_key_id(key_id).await? {
+                if !a.is_expired(Utc::now()) {
+                    return Ok(a);
+                }
+            }
+        }
+
+        let mut url = match Url::parse(key_id) {
+            Ok(u) => u,
+            Err(_) => return Err(CommonError::new(CommonErrorCode::UnexpectedError)),
+        };
+        url.set_fragment(None);
+
+        let actor = match self.fetch_object::<RemoteKeyDocument>(url.as_str(), ACTIVITY_JSON).await? {
+            RemoteKeyDocument::Actor(document) => {
+                if !is_same_host(&document.id, key_id) {
+                    log::warn!("Actor id {} does not match the host of {}", document.id, key_id);
+                    return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+                }
+                let actor = RemoteActor::from(document);
+                self.remote_actor_repository.save(&actor).await?;
+                actor
+            }
+            RemoteKeyDocument::Key(key) => {
+                if !is_same_host(&key.owner, key_id) {
+                    log::warn!("Key owner {} does not match the host of {}", key.owner, key_id);
+                    return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+                }
+                self.refresh(&key.owner).await?
+            }
+        };
+
+        if actor.public_key_id != key_id {
+            log::warn!("Key {} is not owned by {}", key_id, actor.id);
+            return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+        }
+        Ok(actor)
+    }
+
+    async fn fetch_object<T: DeserializeOwned>(&self, url: &str, accept: &str) -> Result<T, CommonError> {
+        let mut res = match Client::default().get(url)
+            .insert_header(("Accept", accept))
+            .send()
+            .await {
+            Ok(r) => r,
+            Err(e) => {
+                log::warn!("Failed to fetch {}: {}", url, e);
+                return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+            }
+        };
+        if !res.status().is_success() {
+            log::warn!("Failed to fetch {}: status {}", url, res.status());
+            return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+        }
+
+        let body = match res.body().limit(1024 * 1024).await {
+            Ok(b) => b,
+            Err(e) => {
+                log::warn!("Failed to read {}: {}", url, e);
+                return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+            }
+        };
+        serde_json::from_slice::<T>(&body).map_err(|e| {
+            log::warn!("Failed to parse {}: {}", url, e);
+            CommonError::new(CommonErrorCode::UnexpectedError)
+        })
+    }
+}
+
+// "@user@host", "user@host" or "acct:user@host"
+fn parse_account(account: &str) -> Option<(&str, &str)> {
+    let account = account.strip_prefix("acct:").unwrap_or(account);
+    let account = account.strip_prefix('@').unwrap_or(account);
+    let (username, host) = account.split_once('@')?;
+    if username.is_empty() || host.is_empty() || host.contains(['@', '/', '?', '#']) {
+        return None;
+    }
+    Some((username, host))
+}
+
+fn is_same_host(a: &str, b: &str) -> bool {
+    match (Url::parse(a), Url::parse(b)) {
+        (Ok(a), Ok(b)) => a.host_str().is_some() && a.host_str() == b.host_str(),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::domain::activity_pub::activity_pub::RemoteWebFinger;
+    use crate::domain::remote_actor::remote_actor_service::parse_account;
+
+    #[test]
+    fn test_parse_account() {
+        assert_eq!(parse_account("foo@remote.example.com"), Some(("foo", "remote.example.com")));
+        assert_eq!(parse_account("@foo@remote.example.com"), Some(("foo", "remote.example.com")));
+        assert_eq!(parse_account("acct:foo@remote.example.com"), Some(("foo", "remote.example.com")));
+        assert_eq!(parse_account("foo"), None);
+        assert_eq!(parse_account("@foo@"), None);
+        assert_eq!(parse_account("foo@remote.example.com/users"), None);
+    }
+
+    #[test]
+    fn test_self_link() {
+        let web_finger: RemoteWebFinger = serde_json::from_str(r#"{
+            "subject": "acct:foo@remote.example.com",
+            "links": [
+                {"rel": "http://webfinger.net/rel/profile-page", "type": "text/html", "href": "https://remote.example.com/@foo"},
+                {"rel": "self", "type": "application/activity+json", "href": "https://remote.example.com/users/foo"},
+                {"rel": "http://ostatus.org/schema/1.0/subscribe", "template": "https://remote.example.com/authorize_interaction?uri={uri}"}
+            ]
+        }"#).unwrap();
+        assert_eq!(web_finger.self_link().unwrap(), "https://remote.example.com/users/foo");
+
+        let web_finger: RemoteWebFinger = serde_json::from_str(r#"{"subject": "acct:foo@remote.example.com"}"#).unwrap();
+        assert!(web_finger.self_link().is_none());
+    }
+}
@@ -0,0 +1,64 @@
+use chrono::{DateTime, Utc};
+
+// note received from remote account
+#[derive(Clone, Debug)]
+pub struct RemoteNote {
+    pub id: i32,
+    pub user_id: String,
+    pub object_id: String,
+    pub actor: String,
+    pub content: String,
+    pub summary: Option<String>,
+    pub in_reply_to: Option<String>,
+    pub published: DateTime<Utc>,
+    pub created_at: DateTime<Utc>,
+}
+
+pub struct RemoteNotesPage {
+    pub notes: Vec<RemoteNote>,
+    pub total: u64,
+}
+
+impl RemoteNote {
+    pub fn new(user_id: &str, object_id: &str, actor: &str, content: &str, published: DateTime<Utc>) -> Self {
+        RemoteNote {
+            id: 0,
+            user_id: user_id.to_string(),
+            object_id: object_id.to_string(),
+            actor: actor.to_string(),
+            content: sanitize(content),
+            summary: None,
+            in_reply_to: None,
+            published,
+            created_at: Utc::now(),
+        }
+    }
+}
+
+// remove scripts, styles and unknown attributes from html
+pub fn sanitize(html: &str) -> String {
+    ammonia::clean(html)
+}
+
+#[cfg(test)]
+mod test {
+    use chrono::Utc;
+    use crate::domain::remote_note::remote_note::RemoteNote;
+
+    #[test]
+    fn test_new_remote_note() {
+        let note = RemoteNote::new(
+            "abcd1234",
+            "https://remote.example.com/notes/1",
+            "https://remote.example.com/users/foo",
+            r#"<p onclick="alert(1)">Hello, <a href="https://remote.example.com/">world</a>!<script>alert(1)</script></p>"#,
+            Utc::now(),
+        );
+
+        assert_eq!(note.user_id, "abcd1234");
+        assert_eq!(
+            note.content,
+            r#"<p>Hello, <a href="https://remote.example.com/" rel="noopener noreferrer">world</a>!</p>"#
+        );
+    }
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::domain::error::CommonError;
+use crate::domain::note::paging::NotesPagingParams;
+use crate::domain::remote_note::remote_note::{RemoteNote, RemoteNotesPage};
+
+#[async_trait]
+pub trait RemoteNoteRepository: Sync + Send {
+    async fn add(&self, new_note: &RemoteNote) -> Result<(), CommonError>;
+    async fn list(&self, user_id: &str, paging_params: &NotesPagingParams) -> Result<RemoteNotesPage, CommonError>;
+}
@@ -4,6 +4,7 @@ use openssl::hash::MessageDigest;
 use openssl::pkey::{PKey, Private, Public};
 use openssl::rsa::{Padding, Rsa};
 use openssl::sign::Signer;
+use serde::{Deserialize, Serialize};
 use crate::domain::id_generator::IDGenerator;
 
 
@@ -12,11 +13,29 @@ pub struct User {
     pub id: String,
     pub username: String,
     pub display_name: String,
+    // only the number of followers and followings is public
+    pub hide_collections: bool,
+    // followers are approved manually
+    pub locked: bool,
+    // bio
+    pub summary: String,
+    pub avatar_url: Option<String>,
+    pub header_url: Option<String>,
+    pub fields: Vec<ProfileField>,
     pub created_at: DateTime<Utc>,
     pub updated_at: DateTime<Utc>,
     pub key_pair: UserRsaKey,
 }
 
+// name/value pair shown on the profile
+#[derive(Clone, Debug, Eq, PartialEq, Serialize, Deserialize)]
+pub struct ProfileField {
+    pub name: String,
+    pub value: String,
+}
+
+pub const MAX_PROFILE_FIELDS: usize = 4;
+
 #[derive(Clone, Debug)]
 pub struct UserRsaKey {
     pub private_key: PKey<Private>,
@@ -28,23 +47,30 @@ impl User {
         let id = IDGenerator::generate(8);
         let now = Utc::now();
 
-        // generate rsa key pair
-        let rsa_key = Rsa::generate(2048).unwrap();
-        let key_pair = UserRsaKey {
-            private_key: PKey::private_key_from_pem(&rsa_key.private_key_to_pem().unwrap()).unwrap(),
-            public_key: PKey::public_key_from_pem(&rsa_key.public_key_to_pem().unwrap()).unwrap(),
-        };
+        let key_pair = UserRsaKey::generate();
 
         User {
             id,
             username: username.to_string(),
             display_name: display_name.to_string(),
+            hide_collections: false,
+            locked: false,
+            summary: "".to_string(),
+            avatar_url: None,
+            header_url: None,
+            fields: vec![],
             created_at: now.clone(),
             updated_at: now.clone(),
             key_pair,
         }
     }
 
+    // replace the key pair, e.g. when the private key is leaked
+    pub fn rotate_key(&mut self, now: DateTime<Utc>) {
+        self.key_pair = UserRsaKey::generate();
+        self.updated_at = now;
+    }
+
     pub fn sign(&self, data: &[u8]) -> String {
         let mut signer = Signer::new(MessageDigest::sha256(), &self.key_pair.private_key).unwrap();
         signer.set_rsa_padding(Padding::PKCS1).unwrap();
@@ -56,8 +82,20 @@ impl User {
     }
 }
 
+impl UserRsaKey {
+    pub fn generate() -> Self {
+        let rsa_key = Rsa::generate(2048).unwrap();
+        UserRsaKey {
+            private_key: PKey::private_key_from_pem(&rsa_key.private_key_to_pem().unwrap()).unwrap(),
+            public_key: PKey::public_key_from_pem(&rsa_key.public_key_to_pem().unwrap()).unwrap(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod test {
+    use base64::{Engine as _, engine::general_purpose};
+    use chrono::Utc;
     use openssl::hash::MessageDigest;
     use openssl::sign::{Signer, Verifier};
     use crate::domain::user::user::User;
@@ -70,6 +108,10 @@ mod test {
         assert_ne!(user.id, "");
         assert_eq!(user.username, "john");
         assert_eq!(user.display_name, "John Doe");
+        assert!(!user.hide_collections);
+        assert!(!user.locked);
+        assert_eq!(user.summary, "");
+        assert!(user.fields.is_empty());
 
         // check key pair
         let data = b"hello, world!";
@@ -80,4 +122,22 @@ mod test {
         let _ = verifier.update(data);
         assert!(verifier.verify(&signature).unwrap());
     }
+
+    #[test]
+    fn test_rotate_key() {
+        let mut user = User::new("john", "John Doe");
+        let old_public_key = user.key_pair.public_key.public_key_to_pem().unwrap();
+        let now = Utc::now();
+        user.rotate_key(now);
+
+        assert_ne!(user.key_pair.public_key.public_key_to_pem().unwrap(), old_public_key);
+        assert_eq!(user.updated_at, now);
+
+        // signed with the new key
+        let data = b"hello, world!";
+        let signature = general_purpose::STANDARD.decode(user.sign(data)).unwrap();
+        let mut verifier = Verifier::new(MessageDigest::sha256(), &user.key_pair.public_key).unwrap();
+        let _ = verifier.update(data);
+        assert!(verifier.verify(&signature).unwrap());
+    }
 }
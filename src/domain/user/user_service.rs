@@ -1,15 +1,21 @@
 use std::sync::Arc;
 use crate::domain::error::CommonError;
 use crate::domain::user::user_repository::UserRepository;
+use crate::domain::user::user_tombstone_repository::UserTombstoneRepository;
 
 pub struct UserService {
     user_repository: Arc<dyn UserRepository>,
+    user_tombstone_repository: Arc<dyn UserTombstoneRepository>,
 }
 
 impl UserService {
-    pub fn new(user_repository: Arc<dyn UserRepository>) -> Self {
+    pub fn new(
+        user_repository: Arc<dyn UserRepository>,
+        user_tombstone_repository: Arc<dyn UserTombstoneRepository>,
+    ) -> Self {
         UserService {
             user_repository,
+            user_tombstone_repository,
         }
     }
 
@@ -18,7 +24,8 @@ impl UserService {
         match finder {
             Ok(res) => match res {
                 Some(_) => Ok(true),
-                None => Ok(false),
+                // remote servers may still cache the deleted account
+                None => Ok(self.user_tombstone_repository.find_by_username(username).await?.is_some()),
             }
             Err(e) => Err(e)
         }
@@ -0,0 +1,20 @@
+use chrono::{DateTime, Utc};
+use crate::domain::user::user::User;
+
+// deleted account, its id and username are not reused
+#[derive(Clone, Debug)]
+pub struct UserTombstone {
+    pub user_id: String,
+    pub username: String,
+    pub deleted_at: DateTime<Utc>,
+}
+
+impl UserTombstone {
+    pub fn new(user: &User) -> Self {
+        UserTombstone {
+            user_id: user.id.clone(),
+            username: user.username.clone(),
+            deleted_at: Utc::now(),
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use async_trait::async_trait;
+use crate::domain::error::CommonError;
+use crate::domain::user::user_tombstone::UserTombstone;
+
+#[async_trait]
+pub trait UserTombstoneRepository: Sync + Send {
+    async fn add(&self, tombstone: &UserTombstone) -> Result<(), CommonError>;
+    async fn find(&self, user_id: &str) -> Result<Option<UserTombstone>, CommonError>;
+    async fn find_by_username(&self, username: &str) -> Result<Option<UserTombstone>, CommonError>;
+}
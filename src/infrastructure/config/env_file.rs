@@ -1,7 +1,13 @@
 use std::env;
 use url::Url;
 use crate::domain::app_config::AppConfig;
-use crate::domain::constants::{ADMIN_API_KEY, APP_URL, DATABASE_URL};
+use crate::domain::constants::{
+    ADMIN_API_KEY, APP_URL, DATABASE_URL,
+    DEFAULT_DELIVERY_CONCURRENCY, DEFAULT_DELIVERY_HOST_CONCURRENCY, DEFAULT_DELIVERY_JOB_RETENTION, DEFAULT_DELIVERY_MAX_ATTEMPTS, DEFAULT_DELIVERY_RETRY_BASE, DEFAULT_DELIVERY_TIMEOUT,
+    DEFAULT_INSTANCE_UNREACHABLE_THRESHOLD, DEFAULT_PROCESSED_ACTIVITY_RETENTION, DEFAULT_SIGNATURE_CLOCK_SKEW,
+    DELIVERY_CONCURRENCY, DELIVERY_HOST_CONCURRENCY, DELIVERY_JOB_RETENTION, DELIVERY_MAX_ATTEMPTS, DELIVERY_RETRY_BASE, DELIVERY_TIMEOUT,
+    INSTANCE_UNREACHABLE_THRESHOLD, PROCESSED_ACTIVITY_RETENTION, SIGNATURE_CLOCK_SKEW,
+};
 
 pub async fn load_app_config() -> AppConfig {
     let environment = match env::var("ENV") {
@@ -12,6 +18,42 @@ pub async fn load_app_config() -> AppConfig {
 
     let app_url = dotenv::var(APP_URL).expect(&*format!("{} must be set", APP_URL));
     let app_url_parsed = Url::parse(&app_url).expect("Invalid APP_URL format");
+    let signature_clock_skew = match dotenv::var(SIGNATURE_CLOCK_SKEW) {
+        Ok(val) => val.parse().expect("SIGNATURE_CLOCK_SKEW must be an integer"),
+        Err(_) => DEFAULT_SIGNATURE_CLOCK_SKEW,
+    };
+    let processed_activity_retention = match dotenv::var(PROCESSED_ACTIVITY_RETENTION) {
+        Ok(val) => val.parse().expect("PROCESSED_ACTIVITY_RETENTION must be an integer"),
+        Err(_) => DEFAULT_PROCESSED_ACTIVITY_RETENTION,
+    };
+    let delivery_max_attempts = match dotenv::var(DELIVERY_MAX_ATTEMPTS) {
+        Ok(val) => val.parse().expect("DELIVERY_MAX_ATTEMPTS must be an integer"),
+        Err(_) => DEFAULT_DELIVERY_MAX_ATTEMPTS,
+    };
+    let delivery_retry_base = match dotenv::var(DELIVERY_RETRY_BASE) {
+        Ok(val) => val.parse().expect("DELIVERY_RETRY_BASE must be an integer"),
+        Err(_) => DEFAULT_DELIVERY_RETRY_BASE,
+    };
+    let delivery_concurrency = match dotenv::var(DELIVERY_CONCURRENCY) {
+        Ok(val) => val.parse().expect("DELIVERY_CONCURRENCY must be a positive integer"),
+        Err(_) => DEFAULT_DELIVERY_CONCURRENCY,
+    };
+    let delivery_host_concurrency = match dotenv::var(DELIVERY_HOST_CONCURRENCY) {
+        Ok(val) => val.parse().expect("DELIVERY_HOST_CONCURRENCY must be a positive integer"),
+        Err(_) => DEFAULT_DELIVERY_HOST_CONCURRENCY,
+    };
+    let delivery_timeout = match dotenv::var(DELIVERY_TIMEOUT) {
+        Ok(val) => val.parse().expect("DELIVERY_TIMEOUT must be a positive integer"),
+        Err(_) => DEFAULT_DELIVERY_TIMEOUT,
+    };
+    let delivery_job_retention = match dotenv::var(DELIVERY_JOB_RETENTION) {
+        Ok(val) => val.parse().expect("DELIVERY_JOB_RETENTION must be an integer"),
+        Err(_) => DEFAULT_DELIVERY_JOB_RETENTION,
+    };
+    let instance_unreachable_threshold = match dotenv::var(INSTANCE_UNREACHABLE_THRESHOLD) {
+        Ok(val) => val.parse().expect("INSTANCE_UNREACHABLE_THRESHOLD must be an integer"),
+        Err(_) => DEFAULT_INSTANCE_UNREACHABLE_THRESHOLD,
+    };
 
     AppConfig {
         environment,
@@ -19,5 +61,14 @@ pub async fn load_app_config() -> AppConfig {
         app_url_host: app_url_parsed.host().expect("Failed to extrace hostname from APP_URL").to_string(),
         admin_api_key: dotenv::var(ADMIN_API_KEY).expect(&*format!("{} must be set", ADMIN_API_KEY)),
         database_url: dotenv::var(DATABASE_URL).expect(&*format!("{} must be set", DATABASE_URL)),
+        signature_clock_skew,
+        processed_activity_retention,
+        delivery_max_attempts,
+        delivery_retry_base,
+        delivery_concurrency,
+        delivery_host_concurrency,
+        delivery_timeout,
+        delivery_job_retention,
+        instance_unreachable_threshold,
     }
 }
@@ -0,0 +1,28 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+use crate::domain::block::block::Block;
+use crate::infrastructure::databases::entities::block;
+
+impl From<&Block> for block::ActiveModel {
+    fn from(new_block: &Block) -> Self {
+        block::ActiveModel {
+            id: Default::default(),
+            user_id: Set(new_block.user_id.clone()),
+            actor: Set(new_block.actor.clone()),
+            object: Set(new_block.object.clone()),
+            created_at: Set(new_block.created_at.to_rfc3339()),
+        }
+    }
+}
+
+impl From<block::Model> for Block {
+    fn from(model: block::Model) -> Self {
+        Block {
+            id: model.id,
+            user_id: model.user_id,
+            actor: model.actor,
+            object: model.object,
+            created_at: DateTime::parse_from_rfc3339(&model.created_at).unwrap().with_timezone(&Utc),
+        }
+    }
+}
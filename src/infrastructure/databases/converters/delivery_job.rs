@@ -0,0 +1,62 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+use crate::domain::delivery::delivery_job::{DeliveryJob, DeliveryJobStatus};
+use crate::infrastructure::databases::entities::delivery_job;
+
+impl From<&DeliveryJob> for delivery_job::ActiveModel {
+    fn from(new_job: &DeliveryJob) -> Self {
+        delivery_job::ActiveModel {
+            id: Default::default(),
+            user_id: Set(new_job.user_id.clone()),
+            inbox: Set(new_job.inbox.clone()),
+            body: Set(new_job.body.clone()),
+            status: Set(new_job.status.into()),
+            attempts: Set(new_job.attempts),
+            next_attempt_at: Set(new_job.next_attempt_at.to_rfc3339()),
+            last_status: Set(new_job.last_status),
+            last_error: Set(new_job.last_error.clone()),
+            created_at: Set(new_job.created_at.to_rfc3339()),
+            updated_at: Set(new_job.updated_at.to_rfc3339()),
+        }
+    }
+}
+
+impl From<delivery_job::Model> for DeliveryJob {
+    fn from(value: delivery_job::Model) -> Self {
+        DeliveryJob {
+            id: value.id,
+            user_id: value.user_id,
+            inbox: value.inbox,
+            body: value.body,
+            status: value.status.into(),
+            attempts: value.attempts,
+            next_attempt_at: DateTime::parse_from_rfc3339(&value.next_attempt_at).unwrap().with_timezone(&Utc),
+            last_status: value.last_status,
+            last_error: value.last_error,
+            created_at: DateTime::parse_from_rfc3339(&value.created_at).unwrap().with_timezone(&Utc),
+            updated_at: DateTime::parse_from_rfc3339(&value.updated_at).unwrap().with_timezone(&Utc),
+        }
+    }
+}
+
+impl From<DeliveryJobStatus> for i32 {
+    fn from(value: DeliveryJobStatus) -> Self {
+        match value {
+            DeliveryJobStatus::PENDING => 1,
+            DeliveryJobStatus::DELIVERED => 2,
+            DeliveryJobStatus::FAILED => 3,
+            DeliveryJobStatus::UNKNOWN => 0,
+        }
+    }
+}
+
+impl From<i32> for DeliveryJobStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => DeliveryJobStatus::PENDING,
+            2 => DeliveryJobStatus::DELIVERED,
+            3 => DeliveryJobStatus::FAILED,
+            _ => DeliveryJobStatus::UNKNOWN,
+        }
+    }
+}
@@ -0,0 +1,30 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+use crate::domain::follow_request::follow_request::FollowRequest;
+use crate::infrastructure::databases::entities::follow_request;
+
+impl From<&FollowRequest> for follow_request::ActiveModel {
+    fn from(new_request: &FollowRequest) -> Self {
+        follow_request::ActiveModel {
+            id: Default::default(),
+            user_id: Set(new_request.user_id.clone()),
+            actor: Set(new_request.actor.clone()),
+            object: Set(new_request.object.clone()),
+            inbox: Set(new_request.inbox.clone()),
+            created_at: Set(new_request.created_at.to_rfc3339()),
+        }
+    }
+}
+
+impl From<follow_request::Model> for FollowRequest {
+    fn from(model: follow_request::Model) -> Self {
+        FollowRequest {
+            id: model.id,
+            user_id: model.user_id,
+            actor: model.actor,
+            object: model.object,
+            inbox: model.inbox,
+            created_at: DateTime::parse_from_rfc3339(&model.created_at).unwrap().with_timezone(&Utc),
+        }
+    }
+}
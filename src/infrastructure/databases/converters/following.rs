@@ -0,0 +1,54 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+use crate::domain::following::following::{Following, FollowingStatus};
+use crate::infrastructure::databases::entities::following;
+
+impl From<&Following> for following::ActiveModel {
+    fn from(new_following: &Following) -> Self {
+        following::ActiveModel {
+            id: Default::default(),
+            user_id: Set(new_following.user_id.clone()),
+            actor: Set(new_following.actor.clone()),
+            object: Set(new_following.object.clone()),
+            status: Set(new_following.status.into()),
+            created_at: Set(new_following.created_at.to_rfc3339()),
+            updated_at: Set(new_following.updated_at.to_rfc3339()),
+        }
+    }
+}
+
+impl From<following::Model> for Following {
+    fn from(value: following::Model) -> Self {
+        Following {
+            id: value.id,
+            user_id: value.user_id,
+            actor: value.actor,
+            object: value.object,
+            status: value.status.into(),
+            created_at: DateTime::parse_from_rfc3339(&value.created_at).unwrap().with_timezone(&Utc),
+            updated_at: DateTime::parse_from_rfc3339(&value.updated_at).unwrap().with_timezone(&Utc),
+        }
+    }
+}
+
+impl From<FollowingStatus> for i32 {
+    fn from(value: FollowingStatus) -> Self {
+        match value {
+            FollowingStatus::PENDING => 1,
+            FollowingStatus::ACCEPTED => 2,
+            FollowingStatus::REJECTED => 3,
+            FollowingStatus::UNKNOWN => 0,
+        }
+    }
+}
+
+impl From<i32> for FollowingStatus {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => FollowingStatus::PENDING,
+            2 => FollowingStatus::ACCEPTED,
+            3 => FollowingStatus::REJECTED,
+            _ => FollowingStatus::UNKNOWN,
+        }
+    }
+}
@@ -0,0 +1,31 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+use crate::domain::instance::instance_health::InstanceHealth;
+use crate::infrastructure::databases::entities::instance_health;
+
+impl From<&InstanceHealth> for instance_health::ActiveModel {
+    fn from(health: &InstanceHealth) -> Self {
+        instance_health::ActiveModel {
+            host: Set(health.host.clone()),
+            consecutive_failures: Set(health.consecutive_failures),
+            last_success_at: Set(health.last_success_at.map(|d| d.to_rfc3339())),
+            last_failure_at: Set(health.last_failure_at.map(|d| d.to_rfc3339())),
+            unreachable_since: Set(health.unreachable_since.map(|d| d.to_rfc3339())),
+            updated_at: Set(health.updated_at.to_rfc3339()),
+        }
+    }
+}
+
+impl From<instance_health::Model> for InstanceHealth {
+    fn from(value: instance_health::Model) -> Self {
+        let parse = |d: String| DateTime::parse_from_rfc3339(&d).unwrap().with_timezone(&Utc);
+        InstanceHealth {
+            host: value.host,
+            consecutive_failures: value.consecutive_failures,
+            last_success_at: value.last_success_at.map(parse),
+            last_failure_at: value.last_failure_at.map(parse),
+            unreachable_since: value.unreachable_since.map(parse),
+            updated_at: parse(value.updated_at),
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use chrono::{DateTime, Utc};
 use sea_orm::ActiveValue::Set;
-use crate::domain::note::note::{Note, NoteStatus};
+use crate::domain::note::note::{Note, NoteStatus, NoteVisibility};
 use crate::infrastructure::databases::entities::note;
 
 impl From<&Note> for note::ActiveModel {
@@ -10,6 +10,8 @@ impl From<&Note> for note::ActiveModel {
             user_id: Set(note.user_id.clone()),
             content: Set(note.content.clone()),
             status: Set(note.status.into()),
+            visibility: Set(note.visibility.into()),
+            pinned_at: Set(note.pinned_at.map(|t| t.to_rfc3339())),
             created_at: Set(note.created_at.to_rfc3339()),
             updated_at: Set(note.updated_at.to_rfc3339()),
         }
@@ -22,6 +24,8 @@ pub fn restore(note: &note::Model) -> Note {
         user_id: note.user_id.clone(),
         content: note.content.clone(),
         status: note.status.into(),
+        visibility: note.visibility.into(),
+        pinned_at: note.pinned_at.as_ref().map(|t| DateTime::parse_from_rfc3339(t).unwrap().with_timezone(&Utc)),
         created_at: DateTime::parse_from_rfc3339(&note.created_at).unwrap().with_timezone(&Utc),
         updated_at: DateTime::parse_from_rfc3339(&note.updated_at).unwrap().with_timezone(&Utc),
     }
@@ -46,3 +50,25 @@ impl From<i32> for NoteStatus {
         }
     }
 }
+
+impl From<NoteVisibility> for i32 {
+    fn from(value: NoteVisibility) -> Self {
+        match value {
+            NoteVisibility::PUBLIC => 1,
+            NoteVisibility::UNLISTED => 2,
+            NoteVisibility::FOLLOWERS => 3,
+            NoteVisibility::UNKNOWN => 0,
+        }
+    }
+}
+
+impl From<i32> for NoteVisibility {
+    fn from(value: i32) -> Self {
+        match value {
+            1 => NoteVisibility::PUBLIC,
+            2 => NoteVisibility::UNLISTED,
+            3 => NoteVisibility::FOLLOWERS,
+            _ => NoteVisibility::UNKNOWN,
+        }
+    }
+}
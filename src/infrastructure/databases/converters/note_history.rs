@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+use crate::domain::note::note_history::NoteHistory;
+use crate::infrastructure::databases::entities::note_history;
+
+impl From<&NoteHistory> for note_history::ActiveModel {
+    fn from(history: &NoteHistory) -> Self {
+        note_history::ActiveModel {
+            id: Default::default(),
+            note_id: Set(history.note_id.clone()),
+            content: Set(history.content.clone()),
+            created_at: Set(history.created_at.to_rfc3339()),
+        }
+    }
+}
+
+pub fn restore(history: &note_history::Model) -> NoteHistory {
+    NoteHistory {
+        id: history.id,
+        note_id: history.note_id.clone(),
+        content: history.content.clone(),
+        created_at: DateTime::parse_from_rfc3339(&history.created_at).unwrap().with_timezone(&Utc),
+    }
+}
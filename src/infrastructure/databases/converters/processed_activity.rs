@@ -0,0 +1,14 @@
+use sea_orm::ActiveValue::Set;
+use crate::domain::processed_activity::processed_activity::ProcessedActivity;
+use crate::infrastructure::databases::entities::processed_activity;
+
+impl From<&ProcessedActivity> for processed_activity::ActiveModel {
+    fn from(processed: &ProcessedActivity) -> Self {
+        processed_activity::ActiveModel {
+            id: Default::default(),
+            activity_id: Set(processed.activity_id.clone()),
+            user_id: Set(processed.user_id.clone()),
+            created_at: Set(processed.created_at.to_rfc3339()),
+        }
+    }
+}
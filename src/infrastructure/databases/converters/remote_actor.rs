@@ -0,0 +1,32 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+use crate::domain::remote_actor::remote_actor::RemoteActor;
+use crate::infrastructure::databases::entities::remote_actor;
+
+impl From<&RemoteActor> for remote_actor::ActiveModel {
+    fn from(actor: &RemoteActor) -> Self {
+        remote_actor::ActiveModel {
+            id: Set(actor.id.clone()),
+            preferred_username: Set(actor.preferred_username.clone()),
+            inbox: Set(actor.inbox.clone()),
+            shared_inbox: Set(actor.shared_inbox.clone()),
+            public_key_id: Set(actor.public_key_id.clone()),
+            public_key_pem: Set(actor.public_key_pem.clone()),
+            fetched_at: Set(actor.fetched_at.to_rfc3339()),
+        }
+    }
+}
+
+impl From<remote_actor::Model> for RemoteActor {
+    fn from(value: remote_actor::Model) -> Self {
+        RemoteActor {
+            id: value.id,
+            preferred_username: value.preferred_username,
+            inbox: value.inbox,
+            shared_inbox: value.shared_inbox,
+            public_key_id: value.public_key_id,
+            public_key_pem: value.public_key_pem,
+            fetched_at: DateTime::parse_from_rfc3339(&value.fetched_at).unwrap().with_timezone(&Utc),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+use crate::domain::remote_note::remote_note::RemoteNote;
+use crate::infrastructure::databases::entities::remote_note;
+
+impl From<&RemoteNote> for remote_note::ActiveModel {
+    fn from(note: &RemoteNote) -> Self {
+        remote_note::ActiveModel {
+            id: Default::default(),
+            user_id: Set(note.user_id.clone()),
+            object_id: Set(note.object_id.clone()),
+            actor: Set(note.actor.clone()),
+            content: Set(note.content.clone()),
+            summary: Set(note.summary.clone()),
+            in_reply_to: Set(note.in_reply_to.clone()),
+            published: Set(note.published.to_rfc3339()),
+            created_at: Set(note.created_at.to_rfc3339()),
+        }
+    }
+}
+
+impl From<remote_note::Model> for RemoteNote {
+    fn from(value: remote_note::Model) -> Self {
+        RemoteNote {
+            id: value.id,
+            user_id: value.user_id,
+            object_id: value.object_id,
+            actor: value.actor,
+            content: value.content,
+            summary: value.summary,
+            in_reply_to: value.in_reply_to,
+            published: DateTime::parse_from_rfc3339(&value.published).unwrap().with_timezone(&Utc),
+            created_at: DateTime::parse_from_rfc3339(&value.created_at).unwrap().with_timezone(&Utc),
+        }
+    }
+}
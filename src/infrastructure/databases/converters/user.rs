@@ -10,6 +10,12 @@ impl From<&User> for user::ActiveModel {
             id: Set(user.id.clone()),
             username: Set(user.username.clone()),
             display_name: Set(user.display_name.clone()),
+            hide_collections: Set(user.hide_collections),
+            locked: Set(user.locked),
+            summary: Set(user.summary.clone()),
+            avatar_url: Set(user.avatar_url.clone()),
+            header_url: Set(user.header_url.clone()),
+            fields: Set(serde_json::to_string(&user.fields).unwrap()),
             created_at: Set(user.created_at.to_rfc3339()),
             updated_at: Set(user.updated_at.to_rfc3339()),
         }
@@ -43,6 +49,12 @@ pub fn restore(user: &user::Model, key_pair: &user_rsa_key::Model) -> User {
         id: user.id.clone(),
         username: user.username.clone(),
         display_name: user.display_name.clone(),
+        hide_collections: user.hide_collections,
+        locked: user.locked,
+        summary: user.summary.clone(),
+        avatar_url: user.avatar_url.clone(),
+        header_url: user.header_url.clone(),
+        fields: serde_json::from_str(&user.fields).unwrap_or_default(),
         created_at: DateTime::parse_from_rfc3339(&user.created_at).unwrap().with_timezone(&Utc),
         updated_at: DateTime::parse_from_rfc3339(&user.updated_at).unwrap().with_timezone(&Utc),
         key_pair: (*key_pair).clone().into(),
@@ -0,0 +1,24 @@
+use chrono::{DateTime, Utc};
+use sea_orm::ActiveValue::Set;
+use crate::domain::user::user_tombstone::UserTombstone;
+use crate::infrastructure::databases::entities::user_tombstone;
+
+impl From<&UserTombstone> for user_tombstone::ActiveModel {
+    fn from(tombstone: &UserTombstone) -> Self {
+        user_tombstone::ActiveModel {
+            user_id: Set(tombstone.user_id.clone()),
+            username: Set(tombstone.username.clone()),
+            deleted_at: Set(tombstone.deleted_at.to_rfc3339()),
+        }
+    }
+}
+
+impl From<user_tombstone::Model> for UserTombstone {
+    fn from(value: user_tombstone::Model) -> Self {
+        UserTombstone {
+            user_id: value.user_id,
+            username: value.username,
+            deleted_at: DateTime::parse_from_rfc3339(&value.deleted_at).unwrap().with_timezone(&Utc),
+        }
+    }
+}
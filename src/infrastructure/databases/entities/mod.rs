@@ -2,7 +2,18 @@
 
 pub mod prelude;
 
+pub mod block;
+pub mod delivery_job;
+pub mod follow_request;
 pub mod follower;
+pub mod following;
+pub mod instance_health;
 pub mod note;
+pub mod note_history;
+pub mod note_recipient;
+pub mod processed_activity;
+pub mod remote_actor;
+pub mod remote_note;
 pub mod user;
 pub mod user_rsa_key;
+pub mod user_tombstone;
@@ -10,6 +10,8 @@ pub struct Model {
     pub user_id: String,
     pub content: String,
     pub status: i32,
+    pub visibility: i32,
+    pub pinned_at: Option<String>,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -1,6 +1,17 @@
 //! `SeaORM` Entity. Generated by sea-orm-codegen 0.11.3
 
+pub use super::block::Entity as Block;
+pub use super::delivery_job::Entity as DeliveryJob;
+pub use super::follow_request::Entity as FollowRequest;
 pub use super::follower::Entity as Follower;
+pub use super::following::Entity as Following;
+pub use super::instance_health::Entity as InstanceHealth;
 pub use super::note::Entity as Note;
+pub use super::note_history::Entity as NoteHistory;
+pub use super::note_recipient::Entity as NoteRecipient;
+pub use super::processed_activity::Entity as ProcessedActivity;
+pub use super::remote_actor::Entity as RemoteActor;
+pub use super::remote_note::Entity as RemoteNote;
 pub use super::user::Entity as User;
 pub use super::user_rsa_key::Entity as UserRsaKey;
+pub use super::user_tombstone::Entity as UserTombstone;
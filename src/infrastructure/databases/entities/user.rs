@@ -9,6 +9,12 @@ pub struct Model {
     pub id: String,
     pub username: String,
     pub display_name: String,
+    pub hide_collections: bool,
+    pub locked: bool,
+    pub summary: String,
+    pub avatar_url: Option<String>,
+    pub header_url: Option<String>,
+    pub fields: String,
     pub created_at: String,
     pub updated_at: String,
 }
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, Condition, DbConn, QueryOrder};
+use sea_orm::prelude::*;
+use crate::domain::block::block::Block;
+use crate::domain::block::block_repository::BlockRepository;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::infrastructure::databases::entities::block;
+
+pub struct BlockSeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl BlockSeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        BlockSeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl BlockRepository for BlockSeaORMRepository {
+    async fn add(&self, new_block: &Block) -> Result<Block, CommonError> {
+        match block::ActiveModel::from(new_block).insert(&self.db_conn).await {
+            Ok(b) => Ok(b.into()),
+            Err(e) => {
+                log::error!("Failed to insert block: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn list(&self, user_id: &str) -> Result<Vec<Block>, CommonError> {
+        let result = block::Entity::find()
+            .filter(block::Column::UserId.eq(user_id))
+            .order_by_asc(block::Column::Id)
+            .all(&self.db_conn)
+            .await;
+        match result {
+            Ok(l) => Ok(l.into_iter().map(|b| b.into()).collect()),
+            Err(e) => {
+                log::error!("Failed to list blocks: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn get(&self, user_id: &str, block_id: i32) -> Result<Block, CommonError> {
+        let result = block::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(block::Column::UserId.eq(user_id))
+                    .add(block::Column::Id.eq(block_id))
+            )
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(Some(b)) => Ok(b.into()),
+            Ok(None) => Err(CommonError::new(CommonErrorCode::BlockDoesNotExists)),
+            Err(e) => {
+                log::error!("Failed to get block: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn find(&self, user_id: &str, actor: &str) -> Result<Option<Block>, CommonError> {
+        let result = block::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(block::Column::UserId.eq(user_id))
+                    .add(block::Column::Actor.eq(actor))
+            )
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(b) => Ok(b.map(|b| b.into())),
+            Err(e) => {
+                log::error!("Failed to find block: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn delete(&self, block_id: i32) -> Result<(), CommonError> {
+        match block::Entity::delete_by_id(block_id).exec(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to delete block: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+}
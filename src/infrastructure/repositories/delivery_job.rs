@@ -0,0 +1,132 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, DbConn, QueryFilter, QueryOrder, QuerySelect};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::*;
+use crate::domain::delivery::delivery_job::{DeliveryJob, DeliveryJobStatus};
+use crate::domain::delivery::delivery_job_repository::DeliveryJobRepository;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::infrastructure::databases::entities::delivery_job;
+
+pub struct DeliveryJobSeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl DeliveryJobSeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        DeliveryJobSeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl DeliveryJobRepository for DeliveryJobSeaORMRepository {
+    async fn add(&self, new_job: &DeliveryJob) -> Result<(), CommonError> {
+        match delivery_job::ActiveModel::from(new_job).insert(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to insert delivery job: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn list_due(&self, now: DateTime<Utc>, limit: u64) -> Result<Vec<DeliveryJob>, CommonError> {
+        let status: i32 = DeliveryJobStatus::PENDING.into();
+        let result = delivery_job::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(delivery_job::Column::Status.eq(status))
+                    .add(delivery_job::Column::NextAttemptAt.lte(now.to_rfc3339()))
+            )
+            .order_by_asc(delivery_job::Column::NextAttemptAt)
+            .limit(limit)
+            .all(&self.db_conn)
+            .await;
+        match result {
+            Ok(l) => Ok(l.into_iter().map(|j| j.into()).collect()),
+            Err(e) => {
+                log::error!("Failed to list delivery job: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn update(&self, job: &DeliveryJob) -> Result<(), CommonError> {
+        let target = match delivery_job::Entity::find_by_id(job.id).one(&self.db_conn).await {
+            Ok(r) => match r {
+                Some(t) => t,
+                None => {
+                    log::error!("Specified delivery job does not exists");
+                    return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+                }
+            },
+            Err(e) => {
+                log::error!("Failed to get delivery job: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        };
+        let mut target: delivery_job::ActiveModel = target.into();
+
+        target.status = Set(job.status.into());
+        target.attempts = Set(job.attempts);
+        target.next_attempt_at = Set(job.next_attempt_at.to_rfc3339());
+        target.last_status = Set(job.last_status);
+        target.last_error = Set(job.last_error.clone());
+        target.updated_at = Set(job.updated_at.to_rfc3339());
+
+        match target.update(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to update delivery job: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn resume_host(&self, host: &str, now: DateTime<Utc>) -> Result<(), CommonError> {
+        let status: i32 = DeliveryJobStatus::PENDING.into();
+        let result = delivery_job::Entity::update_many()
+            .col_expr(delivery_job::Column::NextAttemptAt, Expr::value(now.to_rfc3339()))
+            .filter(
+                Condition::all()
+                    .add(delivery_job::Column::Status.eq(status))
+                    .add(delivery_job::Column::NextAttemptAt.gt(now.to_rfc3339()))
+                    .add(
+                        Condition::any()
+                            .add(delivery_job::Column::Inbox.like(format!("%://{}/%", host)))
+                            .add(delivery_job::Column::Inbox.like(format!("%://{}:%", host)))
+                    )
+            )
+            .exec(&self.db_conn)
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to resume delivery jobs: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn delete_finished_before(&self, cutoff: DateTime<Utc>) -> Result<(), CommonError> {
+        let finished: Vec<i32> = vec![DeliveryJobStatus::DELIVERED.into(), DeliveryJobStatus::FAILED.into()];
+        let result = delivery_job::Entity::delete_many()
+            .filter(
+                Condition::all()
+                    .add(delivery_job::Column::Status.is_in(finished))
+                    .add(delivery_job::Column::UpdatedAt.lt(cutoff.to_rfc3339()))
+            )
+            .exec(&self.db_conn)
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to delete delivery jobs: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+}
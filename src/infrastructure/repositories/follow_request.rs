@@ -0,0 +1,121 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, Condition, DbConn, QueryOrder};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::*;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::follow_request::follow_request::FollowRequest;
+use crate::domain::follow_request::follow_request_repository::FollowRequestRepository;
+use crate::infrastructure::databases::entities::follow_request;
+
+pub struct FollowRequestSeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl FollowRequestSeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        FollowRequestSeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl FollowRequestRepository for FollowRequestSeaORMRepository {
+    async fn add(&self, new_request: &FollowRequest) -> Result<(), CommonError> {
+        match follow_request::ActiveModel::from(new_request).insert(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to insert follow request: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn list(&self, user_id: &str) -> Result<Vec<FollowRequest>, CommonError> {
+        let result = follow_request::Entity::find()
+            .filter(follow_request::Column::UserId.eq(user_id))
+            .order_by_asc(follow_request::Column::Id)
+            .all(&self.db_conn)
+            .await;
+        match result {
+            Ok(l) => Ok(l.iter().map(|r| -> FollowRequest { r.clone().into() }).collect()),
+            Err(e) => {
+                log::error!("Failed to list follow requests: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn get(&self, user_id: &str, request_id: i32) -> Result<FollowRequest, CommonError> {
+        let result = follow_request::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(follow_request::Column::UserId.eq(user_id))
+                    .add(follow_request::Column::Id.eq(request_id))
+            )
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(Some(r)) => Ok(r.into()),
+            Ok(None) => Err(CommonError::new(CommonErrorCode::FollowRequestDoesNotExists)),
+            Err(e) => {
+                log::error!("Failed to get follow request: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn find(&self, user_id: &str, actor: &str) -> Result<Option<FollowRequest>, CommonError> {
+        let result = follow_request::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(follow_request::Column::UserId.eq(user_id))
+                    .add(follow_request::Column::Actor.eq(actor))
+            )
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(r) => Ok(r.map(|r| r.into())),
+            Err(e) => {
+                log::error!("Failed to find follow request: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn update(&self, request: &FollowRequest) -> Result<(), CommonError> {
+        let target = match follow_request::Entity::find_by_id(request.id).one(&self.db_conn).await {
+            Ok(Some(t)) => t,
+            Ok(None) => {
+                log::error!("Specified follow request does not exists");
+                return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+            }
+            Err(e) => {
+                log::error!("Failed to get follow request: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        };
+        let mut target: follow_request::ActiveModel = target.into();
+
+        target.object = Set(request.object.clone());
+        target.inbox = Set(request.inbox.clone());
+
+        match target.update(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to update follow request: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn delete(&self, request_id: i32) -> Result<(), CommonError> {
+        match follow_request::Entity::delete_by_id(request_id).exec(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to delete follow request: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+}
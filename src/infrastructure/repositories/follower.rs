@@ -1,5 +1,6 @@
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, DbConn};
+use sea_orm::{ActiveModelTrait, Condition, DbConn};
+use sea_orm::ActiveValue::Set;
 use sea_orm::prelude::*;
 use crate::domain::error::{CommonError, CommonErrorCode};
 use crate::domain::follower::follower::Follower;
@@ -44,6 +45,84 @@ impl FollowerRepository for FollowerSeaORMRepository {
         }
     }
 
+    async fn list_by_actor(&self, actor: &str) -> Result<Vec<Follower>, CommonError> {
+        let result = follower::Entity::find()
+            .filter(follower::Column::Actor.eq(actor))
+            .all(&self.db_conn)
+            .await;
+        match result {
+            Ok(l) => Ok(l.iter().map(|f| -> Follower { f.clone().into() }).collect()),
+            Err(e) => {
+                log::error!("Failed to list follower: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn find(&self, user_id: &str, actor: &str) -> Result<Option<Follower>, CommonError> {
+        let result = follower::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(follower::Column::UserId.eq(user_id))
+                    .add(follower::Column::Actor.eq(actor))
+            )
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(r) => Ok(r.map(|f| f.into())),
+            Err(e) => {
+                log::error!("Failed to find follower: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn find_by_object(&self, user_id: &str, object: &str) -> Result<Option<Follower>, CommonError> {
+        let result = follower::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(follower::Column::UserId.eq(user_id))
+                    .add(follower::Column::Object.eq(object))
+            )
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(r) => Ok(r.map(|f| f.into())),
+            Err(e) => {
+                log::error!("Failed to find follower: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn update(&self, follower: &Follower) -> Result<(), CommonError> {
+        let target = match follower::Entity::find_by_id(follower.id).one(&self.db_conn).await {
+            Ok(r) => match r {
+                Some(t) => t,
+                None => {
+                    log::error!("Specified follower does not exists");
+                    return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+                }
+            },
+            Err(e) => {
+                log::error!("Failed to get follower: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        };
+        let mut target: follower::ActiveModel = target.into();
+
+        target.object = Set(follower.object.clone());
+        target.inbox = Set(follower.inbox.clone());
+
+        match target.update(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to update follower: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
     async fn delete(&self, follower_id: i32) -> Result<(), CommonError> {
         match follower::Entity::delete_by_id(follower_id).exec(&self.db_conn).await {
             Ok(_) => Ok(()),
@@ -0,0 +1,153 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, DbConn, QueryFilter};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::*;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::following::following::Following;
+use crate::domain::following::following_repository::FollowingRepository;
+use crate::infrastructure::databases::entities::following;
+
+pub struct FollowingSeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl FollowingSeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        FollowingSeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl FollowingRepository for FollowingSeaORMRepository {
+    async fn add(&self, new_following: &Following) -> Result<Following, CommonError> {
+        match following::ActiveModel::from(new_following).insert(&self.db_conn).await {
+            Ok(f) => Ok(f.into()),
+            Err(e) => {
+                log::error!("Failed to insert following: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn list(&self, user_id: &str) -> Result<Vec<Following>, CommonError> {
+        let result = following::Entity::find()
+            .filter(following::Column::UserId.eq(user_id))
+            .all(&self.db_conn)
+            .await;
+        match result {
+            Ok(l) => Ok(l.into_iter().map(|f| f.into()).collect()),
+            Err(e) => {
+                log::error!("Failed to list following: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn get(&self, user_id: &str, following_id: i32) -> Result<Following, CommonError> {
+        let result = following::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(following::Column::Id.eq(following_id))
+                    .add(following::Column::UserId.eq(user_id))
+            )
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(r) => match r {
+                Some(f) => Ok(f.into()),
+                None => Err(CommonError::new(CommonErrorCode::FollowingDoesNotExists)),
+            },
+            Err(e) => {
+                log::error!("Failed to get following: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn find(&self, user_id: &str, actor: &str) -> Result<Option<Following>, CommonError> {
+        let result = following::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(following::Column::UserId.eq(user_id))
+                    .add(following::Column::Actor.eq(actor))
+            )
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(r) => Ok(r.map(|f| f.into())),
+            Err(e) => {
+                log::error!("Failed to find following: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn find_by_object(&self, object: &str) -> Result<Option<Following>, CommonError> {
+        let result = following::Entity::find()
+            .filter(following::Column::Object.eq(object))
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(r) => Ok(r.map(|f| f.into())),
+            Err(e) => {
+                log::error!("Failed to find following: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn list_by_actor(&self, actor: &str) -> Result<Vec<Following>, CommonError> {
+        let result = following::Entity::find()
+            .filter(following::Column::Actor.eq(actor))
+            .all(&self.db_conn)
+            .await;
+        match result {
+            Ok(l) => Ok(l.into_iter().map(|f| f.into()).collect()),
+            Err(e) => {
+                log::error!("Failed to list following: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn update(&self, following: &Following) -> Result<(), CommonError> {
+        let target = match following::Entity::find_by_id(following.id).one(&self.db_conn).await {
+            Ok(r) => match r {
+                Some(t) => t,
+                None => {
+                    log::error!("Specified following does not exists");
+                    return Err(CommonError::new(CommonErrorCode::UnexpectedError));
+                }
+            },
+            Err(e) => {
+                log::error!("Failed to get following: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        };
+        let mut target: following::ActiveModel = target.into();
+
+        target.object = Set(following.object.clone());
+        target.status = Set(following.status.into());
+        target.updated_at = Set(following.updated_at.to_rfc3339());
+
+        match target.update(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to update following: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn delete(&self, following_id: i32) -> Result<(), CommonError> {
+        match following::Entity::delete_by_id(following_id).exec(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to delete following: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, DbConn, QueryOrder};
+use sea_orm::prelude::*;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::instance::instance_health::InstanceHealth;
+use crate::domain::instance::instance_health_repository::InstanceHealthRepository;
+use crate::infrastructure::databases::entities::instance_health;
+
+pub struct InstanceHealthSeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl InstanceHealthSeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        InstanceHealthSeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl InstanceHealthRepository for InstanceHealthSeaORMRepository {
+    async fn find(&self, host: &str) -> Result<Option<InstanceHealth>, CommonError> {
+        match instance_health::Entity::find_by_id(host).one(&self.db_conn).await {
+            Ok(r) => Ok(r.map(|h| h.into())),
+            Err(e) => {
+                log::error!("Failed to find instance health: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn list(&self) -> Result<Vec<InstanceHealth>, CommonError> {
+        let result = instance_health::Entity::find()
+            .order_by_asc(instance_health::Column::Host)
+            .all(&self.db_conn)
+            .await;
+        match result {
+            Ok(l) => Ok(l.into_iter().map(|h| h.into()).collect()),
+            Err(e) => {
+                log::error!("Failed to list instance health: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn save(&self, health: &InstanceHealth) -> Result<(), CommonError> {
+        let exists = match instance_health::Entity::find_by_id(&health.host).one(&self.db_conn).await {
+            Ok(r) => r.is_some(),
+            Err(e) => {
+                log::error!("Failed to find instance health: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        };
+
+        let model = instance_health::ActiveModel::from(health);
+        let result = if exists {
+            model.update(&self.db_conn).await
+        } else {
+            model.insert(&self.db_conn).await
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to save instance health: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+}
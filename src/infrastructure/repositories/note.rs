@@ -3,7 +3,7 @@ use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, DbConn, QueryFilter, Que
 use sea_orm::ActiveValue::Set;
 use sea_orm::prelude::*;
 use crate::domain::error::{CommonError, CommonErrorCode};
-use crate::domain::note::note::{Note, NoteStatus};
+use crate::domain::note::note::{Note, NoteStatus, NoteVisibility};
 use crate::domain::note::note_repository::NoteRepository;
 use crate::domain::note::paging::{NotesPage, NotesPagingParams};
 use crate::infrastructure::databases::converters::note::restore;
@@ -21,41 +21,33 @@ impl NoteSeaORMRepository {
     }
 }
 
-#[async_trait]
-impl NoteRepository for NoteSeaORMRepository {
-    async fn add(&self, new_note: &Note) -> Result<(), CommonError> {
-        match note::ActiveModel::from(new_note).insert(&self.db_conn).await {
-            Ok(_) => Ok(()),
-            Err(e) => {
-                log::error!("Failed to insert note: {}", e.to_string());
-                return Err(CommonError::new(CommonErrorCode::DBError));
-            }
-        }
-    }
-
-    async fn list(&self, user_id: &String, paging_params: &NotesPagingParams) -> Result<NotesPage, CommonError> {
+impl NoteSeaORMRepository {
+    // number of published notes matching the condition
+    async fn count_by(&self, condition: Condition) -> Result<u64, CommonError> {
         let published: i32 = NoteStatus::PUBLISHED.into();
-
-        // total count
-        let total = note::Entity::find()
-            .filter(note::Column::Status.eq(published))
+        let result = note::Entity::find()
+            .filter(condition.add(note::Column::Status.eq(published)))
             .count(&self.db_conn)
             .await;
-        let total = match total {
-            Ok(c) => c,
+        match result {
+            Ok(c) => Ok(c),
             Err(e) => {
                 log::error!("Failed to get num of notes: {}", e.to_string());
-                return Err(CommonError::new(CommonErrorCode::DBError));
+                Err(CommonError::new(CommonErrorCode::DBError))
             }
-        };
+        }
+    }
+
+    // published notes matching the condition, newest first
+    async fn list_by(&self, condition: Condition, paging_params: &NotesPagingParams) -> Result<NotesPage, CommonError> {
+        let total = self.count_by(condition.clone()).await?;
+
+        let published: i32 = NoteStatus::PUBLISHED.into();
+        let condition = condition.add(note::Column::Status.eq(published));
 
         // select published notes
         let result = note::Entity::find()
-            .filter(
-                Condition::all()
-                    .add(note::Column::UserId.eq(user_id))
-                    .add(note::Column::Status.eq(published))
-            )
+            .filter(condition)
             .order_by_desc(note::Column::CreatedAt)
             .offset(paging_params.offset())
             .limit(paging_params.limit())
@@ -77,6 +69,52 @@ impl NoteRepository for NoteSeaORMRepository {
             notes,
         })
     }
+}
+
+#[async_trait]
+impl NoteRepository for NoteSeaORMRepository {
+    async fn add(&self, new_note: &Note) -> Result<(), CommonError> {
+        match note::ActiveModel::from(new_note).insert(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to insert note: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        }
+    }
+
+    async fn list(&self, user_id: &String, paging_params: &NotesPagingParams) -> Result<NotesPage, CommonError> {
+        self.list_by(Condition::all().add(note::Column::UserId.eq(user_id)), paging_params).await
+    }
+
+    async fn list_public(&self, user_id: &str, paging_params: &NotesPagingParams) -> Result<NotesPage, CommonError> {
+        self.list_by(public_condition(user_id), paging_params).await
+    }
+
+    async fn count_public(&self, user_id: &str) -> Result<u64, CommonError> {
+        self.count_by(public_condition(user_id)).await
+    }
+
+    async fn list_pinned(&self, user_id: &str) -> Result<Vec<Note>, CommonError> {
+        let published: i32 = NoteStatus::PUBLISHED.into();
+        let result = note::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(note::Column::UserId.eq(user_id))
+                    .add(note::Column::Status.eq(published))
+                    .add(note::Column::PinnedAt.is_not_null())
+            )
+            .order_by_desc(note::Column::PinnedAt)
+            .all(&self.db_conn)
+            .await;
+        match result {
+            Ok(l) => Ok(l.iter().map(restore).collect()),
+            Err(e) => {
+                log::error!("Failed to list pinned notes: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
 
     async fn get(&self, user_id: &String, note_id: &String) -> Result<Note, CommonError> {
         let published: i32 = NoteStatus::PUBLISHED.into();
@@ -101,6 +139,16 @@ impl NoteRepository for NoteSeaORMRepository {
         }
     }
 
+    async fn find(&self, note_id: &str) -> Result<Option<Note>, CommonError> {
+        match note::Entity::find_by_id(note_id).one(&self.db_conn).await {
+            Ok(r) => Ok(r.map(|n| restore(&n))),
+            Err(e) => {
+                log::error!("Failed to get note: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
     async fn update(&self, note: &Note) -> Result<(), CommonError> {
         let target = match note::Entity::find_by_id(&note.id).one(&self.db_conn).await {
             Ok(r) => match r {
@@ -117,7 +165,9 @@ impl NoteRepository for NoteSeaORMRepository {
         };
         let mut target: note::ActiveModel = target.into();
 
+        target.content = Set(note.content.clone());
         target.status = Set(note.status.into());
+        target.pinned_at = Set(note.pinned_at.map(|t| t.to_rfc3339()));
         target.updated_at = Set((&note.updated_at).to_rfc3339());
 
         match target.update(&self.db_conn).await {
@@ -129,3 +179,12 @@ impl NoteRepository for NoteSeaORMRepository {
         }
     }
 }
+
+// notes readable without being a follower
+fn public_condition(user_id: &str) -> Condition {
+    let public: i32 = NoteVisibility::PUBLIC.into();
+    let unlisted: i32 = NoteVisibility::UNLISTED.into();
+    Condition::all()
+        .add(note::Column::UserId.eq(user_id))
+        .add(note::Column::Visibility.is_in([public, unlisted]))
+}
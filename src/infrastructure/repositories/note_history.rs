@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbConn, QueryFilter, QueryOrder};
+use sea_orm::prelude::*;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::note::note_history::NoteHistory;
+use crate::domain::note::note_history_repository::NoteHistoryRepository;
+use crate::infrastructure::databases::converters::note_history::restore;
+use crate::infrastructure::databases::entities::note_history;
+
+pub struct NoteHistorySeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl NoteHistorySeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        NoteHistorySeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl NoteHistoryRepository for NoteHistorySeaORMRepository {
+    async fn add(&self, history: &NoteHistory) -> Result<(), CommonError> {
+        match note_history::ActiveModel::from(history).insert(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to insert note history: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn list(&self, note_id: &str) -> Result<Vec<NoteHistory>, CommonError> {
+        let result = note_history::Entity::find()
+            .filter(note_history::Column::NoteId.eq(note_id))
+            .order_by_asc(note_history::Column::Id)
+            .all(&self.db_conn)
+            .await;
+        match result {
+            Ok(l) => Ok(l.iter().map(restore).collect()),
+            Err(e) => {
+                log::error!("Failed to list note history: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use async_trait::async_trait;
+use chrono::Utc;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbConn, QueryFilter, QueryOrder, SqlErr};
+use sea_orm::ActiveValue::Set;
+use sea_orm::prelude::*;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::note::note_recipient_repository::NoteRecipientRepository;
+use crate::infrastructure::databases::entities::note_recipient;
+
+pub struct NoteRecipientSeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl NoteRecipientSeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        NoteRecipientSeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl NoteRecipientRepository for NoteRecipientSeaORMRepository {
+    async fn add(&self, note_id: &str, inboxes: &[String]) -> Result<(), CommonError> {
+        let now = Utc::now();
+        for inbox in inboxes.iter() {
+            let recipient = note_recipient::ActiveModel {
+                id: Default::default(),
+                note_id: Set(note_id.to_string()),
+                inbox: Set(inbox.clone()),
+                created_at: Set(now.to_rfc3339()),
+            };
+            if let Err(e) = recipient.insert(&self.db_conn).await {
+                if let Some(SqlErr::UniqueConstraintViolation(_)) = e.sql_err() {
+                    continue;
+                }
+                log::error!("Failed to insert note recipient: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        }
+        Ok(())
+    }
+
+    async fn list(&self, note_id: &str) -> Result<Vec<String>, CommonError> {
+        let result = note_recipient::Entity::find()
+            .filter(note_recipient::Column::NoteId.eq(note_id))
+            .order_by_asc(note_recipient::Column::Id)
+            .all(&self.db_conn)
+            .await;
+        match result {
+            Ok(l) => Ok(l.into_iter().map(|r| r.inbox).collect()),
+            Err(e) => {
+                log::error!("Failed to list note recipients: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn delete(&self, note_id: &str) -> Result<(), CommonError> {
+        let result = note_recipient::Entity::delete_many()
+            .filter(note_recipient::Column::NoteId.eq(note_id))
+            .exec(&self.db_conn)
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to delete note recipients: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+}
@@ -0,0 +1,69 @@
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, DbConn, QueryFilter, SqlErr};
+use sea_orm::prelude::*;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::processed_activity::processed_activity::ProcessedActivity;
+use crate::domain::processed_activity::processed_activity_repository::ProcessedActivityRepository;
+use crate::infrastructure::databases::entities::processed_activity;
+
+pub struct ProcessedActivitySeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl ProcessedActivitySeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        ProcessedActivitySeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl ProcessedActivityRepository for ProcessedActivitySeaORMRepository {
+    // the unique index decides, so concurrent deliveries of the same activity are recorded once
+    async fn add(&self, processed: &ProcessedActivity) -> Result<bool, CommonError> {
+        match processed_activity::ActiveModel::from(processed).insert(&self.db_conn).await {
+            Ok(_) => Ok(true),
+            Err(e) => match e.sql_err() {
+                Some(SqlErr::UniqueConstraintViolation(_)) => Ok(false),
+                _ => {
+                    log::error!("Failed to insert processed activity: {}", e.to_string());
+                    Err(CommonError::new(CommonErrorCode::DBError))
+                }
+            },
+        }
+    }
+
+    async fn delete(&self, activity_id: &str, user_id: &str) -> Result<(), CommonError> {
+        let result = processed_activity::Entity::delete_many()
+            .filter(
+                Condition::all()
+                    .add(processed_activity::Column::ActivityId.eq(activity_id))
+                    .add(processed_activity::Column::UserId.eq(user_id))
+            )
+            .exec(&self.db_conn)
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to delete processed activity: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn delete_before(&self, before: DateTime<Utc>) -> Result<(), CommonError> {
+        let result = processed_activity::Entity::delete_many()
+            .filter(processed_activity::Column::CreatedAt.lt(before.to_rfc3339()))
+            .exec(&self.db_conn)
+            .await;
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to delete processed activities: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+}
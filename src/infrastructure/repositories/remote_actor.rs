@@ -0,0 +1,70 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, DbConn};
+use sea_orm::prelude::*;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::remote_actor::remote_actor::RemoteActor;
+use crate::domain::remote_actor::remote_actor_repository::RemoteActorRepository;
+use crate::infrastructure::databases::entities::remote_actor;
+
+pub struct RemoteActorSeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl RemoteActorSeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        RemoteActorSeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl RemoteActorRepository for RemoteActorSeaORMRepository {
+    async fn find(&self, actor_id: &str) -> Result<Option<RemoteActor>, CommonError> {
+        match remote_actor::Entity::find_by_id(actor_id).one(&self.db_conn).await {
+            Ok(r) => Ok(r.map(|a| a.into())),
+            Err(e) => {
+                log::error!("Failed to find remote actor: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn find_by_key_id(&self, key_id: &str) -> Result<Option<RemoteActor>, CommonError> {
+        let result = remote_actor::Entity::find()
+            .filter(remote_actor::Column::PublicKeyId.eq(key_id))
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(r) => Ok(r.map(|a| a.into())),
+            Err(e) => {
+                log::error!("Failed to find remote actor: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn save(&self, actor: &RemoteActor) -> Result<(), CommonError> {
+        let exists = match remote_actor::Entity::find_by_id(&actor.id).one(&self.db_conn).await {
+            Ok(r) => r.is_some(),
+            Err(e) => {
+                log::error!("Failed to find remote actor: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        };
+
+        let model = remote_actor::ActiveModel::from(actor);
+        let result = if exists {
+            model.update(&self.db_conn).await
+        } else {
+            model.insert(&self.db_conn).await
+        };
+        match result {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to save remote actor: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+}
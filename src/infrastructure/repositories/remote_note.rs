@@ -0,0 +1,85 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, Condition, DbConn, QueryFilter, QueryOrder, QuerySelect};
+use sea_orm::prelude::*;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::note::paging::NotesPagingParams;
+use crate::domain::remote_note::remote_note::{RemoteNote, RemoteNotesPage};
+use crate::domain::remote_note::remote_note_repository::RemoteNoteRepository;
+use crate::infrastructure::databases::entities::remote_note;
+
+pub struct RemoteNoteSeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl RemoteNoteSeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        RemoteNoteSeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl RemoteNoteRepository for RemoteNoteSeaORMRepository {
+    async fn add(&self, new_note: &RemoteNote) -> Result<(), CommonError> {
+        // same note may be delivered to both shared inbox and personal inbox
+        let exists = remote_note::Entity::find()
+            .filter(
+                Condition::all()
+                    .add(remote_note::Column::UserId.eq(&new_note.user_id))
+                    .add(remote_note::Column::ObjectId.eq(&new_note.object_id))
+            )
+            .one(&self.db_conn)
+            .await;
+        match exists {
+            Ok(Some(_)) => return Ok(()),
+            Ok(None) => {}
+            Err(e) => {
+                log::error!("Failed to find remote note: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        }
+
+        match remote_note::ActiveModel::from(new_note).insert(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to insert remote note: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn list(&self, user_id: &str, paging_params: &NotesPagingParams) -> Result<RemoteNotesPage, CommonError> {
+        // total count
+        let total = remote_note::Entity::find()
+            .filter(remote_note::Column::UserId.eq(user_id))
+            .count(&self.db_conn)
+            .await;
+        let total = match total {
+            Ok(c) => c,
+            Err(e) => {
+                log::error!("Failed to get num of remote notes: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        };
+
+        let result = remote_note::Entity::find()
+            .filter(remote_note::Column::UserId.eq(user_id))
+            .order_by_desc(remote_note::Column::Published)
+            .offset(paging_params.offset())
+            .limit(paging_params.limit())
+            .all(&self.db_conn)
+            .await;
+        let notes = match result {
+            Ok(l) => l.into_iter().map(|n| n.into()).collect(),
+            Err(e) => {
+                log::error!("Failed to list remote notes: {}", e.to_string());
+                return Err(CommonError::new(CommonErrorCode::DBError));
+            }
+        };
+        Ok(RemoteNotesPage {
+            total,
+            notes,
+        })
+    }
+}
@@ -1,12 +1,15 @@
 use async_trait::async_trait;
-use sea_orm::{ActiveModelTrait, DbConn};
+use sea_orm::{ActiveModelTrait, DbConn, DbErr, TransactionTrait};
+use sea_orm::sea_query::Query;
 use sea_orm::ActiveValue::Set;
 use sea_orm::prelude::*;
 use crate::domain::error::{CommonError, CommonErrorCode};
 use crate::domain::user::user::{User};
 use crate::domain::user::user_repository::UserRepository;
 use crate::infrastructure::databases::converters::user::restore;
-use crate::infrastructure::databases::entities::{user, user_rsa_key};
+use crate::infrastructure::databases::entities::{
+    block, delivery_job, follow_request, follower, following, note, note_history, processed_activity, remote_note, user, user_rsa_key,
+};
 
 pub struct UserSeaORMRepository {
     db_conn: DbConn,
@@ -113,34 +116,66 @@ impl UserRepository for UserSeaORMRepository {
         // set all columns
         target.username = Set((&user.username).clone());
         target.display_name = Set((&user.display_name).clone());
+        target.hide_collections = Set(user.hide_collections);
+        target.locked = Set(user.locked);
+        target.summary = Set(user.summary.clone());
+        target.avatar_url = Set(user.avatar_url.clone());
+        target.header_url = Set(user.header_url.clone());
+        target.fields = Set(serde_json::to_string(&user.fields).unwrap());
         target.updated_at = Set((&user.updated_at).to_rfc3339());
 
         // update
         match target.update(&self.db_conn).await {
-            Ok(_) => Ok(()),
+            Ok(_) => {}
             Err(e) => {
                 log::error!("Failed to update user: {}", e.to_string());
                 return Err(CommonError::new(CommonErrorCode::DBError));
             }
         }
-    }
 
-    async fn delete(&self, user_id: &str) -> Result<(), CommonError> {
-        match user_rsa_key::Entity::delete_by_id(user_id).exec(&self.db_conn).await {
-            Ok(_) => {}
+        // key pair may be rotated
+        match user_rsa_key::ActiveModel::from(user).update(&self.db_conn).await {
+            Ok(_) => Ok(()),
             Err(e) => {
-                log::error!("Failed to delete user rsa key: {}", e.to_string());
+                log::error!("Failed to update user rsa key: {}", e.to_string());
                 return Err(CommonError::new(CommonErrorCode::DBError));
             }
         }
+    }
 
-        user::Entity::delete_by_id(user_id).exec(&self.db_conn)
-            .await
-            .map(|_| ())
-            .map_err(|e| {
-                log::error!("Failed to delete user: {}", e.to_string());
-                CommonError::new(CommonErrorCode::DBError)
+    // delete with all data owned by the user
+    async fn delete(&self, user_id: &str) -> Result<(), CommonError> {
+        let user_id = user_id.to_string();
+        let result = self.db_conn.transaction::<_, (), DbErr>(|txn| {
+            Box::pin(async move {
+                note_history::Entity::delete_many()
+                    .filter(note_history::Column::NoteId.in_subquery(
+                        Query::select()
+                            .column(note::Column::Id)
+                            .from(note::Entity)
+                            .and_where(note::Column::UserId.eq(&user_id))
+                            .to_owned()
+                    ))
+                    .exec(txn).await?;
+                note::Entity::delete_many().filter(note::Column::UserId.eq(&user_id)).exec(txn).await?;
+                follower::Entity::delete_many().filter(follower::Column::UserId.eq(&user_id)).exec(txn).await?;
+                follow_request::Entity::delete_many().filter(follow_request::Column::UserId.eq(&user_id)).exec(txn).await?;
+                following::Entity::delete_many().filter(following::Column::UserId.eq(&user_id)).exec(txn).await?;
+                block::Entity::delete_many().filter(block::Column::UserId.eq(&user_id)).exec(txn).await?;
+                remote_note::Entity::delete_many().filter(remote_note::Column::UserId.eq(&user_id)).exec(txn).await?;
+                processed_activity::Entity::delete_many().filter(processed_activity::Column::UserId.eq(&user_id)).exec(txn).await?;
+                // jobs can not be signed anymore
+                delivery_job::Entity::delete_many().filter(delivery_job::Column::UserId.eq(&user_id)).exec(txn).await?;
+                user_rsa_key::Entity::delete_by_id(&user_id).exec(txn).await?;
+                user::Entity::delete_by_id(&user_id).exec(txn).await?;
+                Ok(())
             })
+        }).await;
+
+        result.map_err(|e| {
+            log::error!("Failed to delete user: {}", e.to_string());
+            CommonError::new(CommonErrorCode::DBError)
+        })
     }
 
     async fn find(&self, username: &str) -> Result<Option<User>, CommonError> {
@@ -0,0 +1,56 @@
+use async_trait::async_trait;
+use sea_orm::{ActiveModelTrait, ColumnTrait, DbConn, QueryFilter};
+use sea_orm::prelude::*;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::user::user_tombstone::UserTombstone;
+use crate::domain::user::user_tombstone_repository::UserTombstoneRepository;
+use crate::infrastructure::databases::entities::user_tombstone;
+
+pub struct UserTombstoneSeaORMRepository {
+    db_conn: DbConn,
+}
+
+impl UserTombstoneSeaORMRepository {
+    pub fn new(db_conn: DbConn) -> Self {
+        UserTombstoneSeaORMRepository {
+            db_conn
+        }
+    }
+}
+
+#[async_trait]
+impl UserTombstoneRepository for UserTombstoneSeaORMRepository {
+    async fn add(&self, tombstone: &UserTombstone) -> Result<(), CommonError> {
+        match user_tombstone::ActiveModel::from(tombstone).insert(&self.db_conn).await {
+            Ok(_) => Ok(()),
+            Err(e) => {
+                log::error!("Failed to insert user tombstone: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn find(&self, user_id: &str) -> Result<Option<UserTombstone>, CommonError> {
+        match user_tombstone::Entity::find_by_id(user_id).one(&self.db_conn).await {
+            Ok(r) => Ok(r.map(|t| t.into())),
+            Err(e) => {
+                log::error!("Failed to find user tombstone: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+
+    async fn find_by_username(&self, username: &str) -> Result<Option<UserTombstone>, CommonError> {
+        let result = user_tombstone::Entity::find()
+            .filter(user_tombstone::Column::Username.eq(username))
+            .one(&self.db_conn)
+            .await;
+        match result {
+            Ok(r) => Ok(r.map(|t| t.into())),
+            Err(e) => {
+                log::error!("Failed to find user tombstone: {}", e.to_string());
+                Err(CommonError::new(CommonErrorCode::DBError))
+            }
+        }
+    }
+}
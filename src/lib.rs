@@ -7,6 +7,24 @@ pub mod domain {
     pub mod activity_pub {
         pub mod activity_pub;
         pub mod activity_pub_service;
+        pub mod activity_streams;
+        pub mod http_signature;
+    }
+
+    pub mod block {
+        pub mod block;
+        pub mod block_repository;
+    }
+
+    pub mod delivery {
+        pub mod delivery_job;
+        pub mod delivery_job_repository;
+        pub mod delivery_service;
+    }
+
+    pub mod follow_request {
+        pub mod follow_request;
+        pub mod follow_request_repository;
     }
 
     pub mod follower {
@@ -14,16 +32,48 @@ pub mod domain {
         pub mod follower_repository;
     }
 
+    pub mod following {
+        pub mod following;
+        pub mod following_repository;
+    }
+
+    pub mod instance {
+        pub mod instance_health;
+        pub mod instance_health_repository;
+        pub mod instance_service;
+    }
+
     pub mod note {
         pub mod note;
+        pub mod note_history;
+        pub mod note_history_repository;
+        pub mod note_recipient_repository;
         pub mod note_repository;
         pub mod paging;
     }
 
+    pub mod processed_activity {
+        pub mod processed_activity;
+        pub mod processed_activity_repository;
+    }
+
+    pub mod remote_actor {
+        pub mod remote_actor;
+        pub mod remote_actor_repository;
+        pub mod remote_actor_service;
+    }
+
+    pub mod remote_note {
+        pub mod remote_note;
+        pub mod remote_note_repository;
+    }
+
     pub mod user {
         pub mod user;
         pub mod user_repository;
         pub mod user_service;
+        pub mod user_tombstone;
+        pub mod user_tombstone_repository;
     }
 
     pub mod app_config;
@@ -39,18 +89,39 @@ pub mod infrastructure {
 
     pub mod databases {
         pub mod converters {
+            pub mod block;
+            pub mod delivery_job;
+            pub mod follow_request;
             pub mod follower;
+            pub mod following;
+            pub mod instance_health;
             pub mod note;
+            pub mod note_history;
+            pub mod processed_activity;
+            pub mod remote_actor;
+            pub mod remote_note;
             pub mod user;
+            pub mod user_tombstone;
         }
 
         pub mod entities;
     }
 
     pub mod repositories {
+        pub mod block;
+        pub mod delivery_job;
+        pub mod follow_request;
         pub mod follower;
+        pub mod following;
+        pub mod instance_health;
         pub mod note;
+        pub mod note_history;
+        pub mod note_recipient;
+        pub mod processed_activity;
+        pub mod remote_actor;
+        pub mod remote_note;
         pub mod user;
+        pub mod user_tombstone;
     }
 }
 
@@ -58,8 +129,14 @@ pub mod presentation {
     pub mod controllers {
         pub mod activity_pub;
         pub mod echo;
+        pub mod instance_management;
+        pub mod user_block;
+        pub mod user_follow_request;
+        pub mod user_follower;
+        pub mod user_following;
         pub mod user_note;
         pub mod user_management;
+        pub mod user_received_note;
     }
 
     pub mod errors {
@@ -73,6 +150,12 @@ pub mod presentation {
 
 pub mod usecase {
     pub mod activity_pub;
+    pub mod instance_management;
+    pub mod user_block;
+    pub mod user_follow_request;
+    pub mod user_follower;
+    pub mod user_following;
     pub mod user_note;
     pub mod user_management;
+    pub mod user_received_note;
 }
@@ -1,10 +1,17 @@
 use actix_web::HttpServer;
+use gekidan::app::container::Container;
 use gekidan::app::factory::create_app;
 
 #[actix_web::main]
 async fn main() -> std::io::Result<()> {
     env_logger::init_from_env(env_logger::Env::new().default_filter_or("info"));
 
+    // deliver outgoing activities in background
+    let container = Container::new().await;
+    actix_web::rt::spawn(container.delivery_service.clone().run());
+    // forget old inbound activities
+    actix_web::rt::spawn(container.activity_pub_usecase.clone().run_pruning());
+
     log::info!("Starting server at http://localhost:8080");
 
     HttpServer::new(|| create_app())
@@ -1,12 +1,12 @@
 use std::sync::Arc;
-use actix_web::{HttpResponse, Responder, ResponseError};
-use actix_web::web::{Data, Json, Path, Query};
-use serde::Deserialize;
+use actix_web::{HttpRequest, HttpResponse, Responder, ResponseError};
+use actix_web::web::{Bytes, Data, Path, Query};
+use serde::{Deserialize, Serialize};
 use serde_json::json;
 use crate::app::container::Container;
-use crate::domain::activity_pub::activity_pub::{ActivityNoteBox, InboxActivity};
+use crate::domain::activity_pub::http_signature::InboxRequest;
 use crate::presentation::errors::api::ApiError;
-use crate::usecase::activity_pub::{WebFingerParams};
+use crate::usecase::activity_pub::{CollectionDocument, NoteDocument, WebFingerParams};
 
 pub async fn host_meta(
     container: Data<Arc<Container>>
@@ -26,7 +26,7 @@ pub async fn web_finger(
         Ok(body) => HttpResponse::Ok()
             .content_type("application/jrd+json; charset=utf-8")
             .body(json!(body).to_string()),
-        Err(_) => HttpResponse::NotFound().into(),
+        Err(e) => ApiError::from(e).error_response(),
     }
 }
 
@@ -71,25 +71,135 @@ pub async fn actor_by_user_id(
 pub async fn post_inbox(
     container: Data<Arc<Container>>,
     params: Path<String>,
-    post_data: Json<InboxActivity>,
+    req: HttpRequest,
+    body: Bytes,
+) -> impl Responder {
+    let request = inbox_request(&req, &body);
+    match container.activity_pub_usecase.receive_inbox_activity(&params.into_inner(), &request).await {
+        Ok(_) => HttpResponse::Ok().body("ok"),
+        Err(e) => ApiError::from(e).error_response(),
+    }
+}
+
+pub async fn post_shared_inbox(
+    container: Data<Arc<Container>>,
+    req: HttpRequest,
+    body: Bytes,
 ) -> impl Responder {
-    let activity = post_data.into_inner();
-    match (&container.activity_pub_usecase).process_inbox_activity(&params.into_inner(), &activity).await {
+    let request = inbox_request(&req, &body);
+    match container.activity_pub_usecase.receive_shared_inbox_activity(&request).await {
         Ok(_) => HttpResponse::Ok().body("ok"),
         Err(e) => ApiError::from(e).error_response(),
     }
 }
 
-pub async fn get_outbox() -> impl Responder {
+pub async fn get_note(
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let request = inbox_request(&req, &Bytes::new());
+    match container.activity_pub_usecase.note(&params.into_inner(), &request).await {
+        Ok(NoteDocument::Published(item)) => HttpResponse::Ok()
+            .content_type("application/activity+json; charset=utf-8")
+            .body(json!(item.object).to_string()),
+        Ok(NoteDocument::Deleted(t)) => HttpResponse::Gone()
+            .content_type("application/activity+json; charset=utf-8")
+            .body(json!(t).to_string()),
+        Err(e) => ApiError::from(e).error_response(),
+    }
+}
+
+pub async fn get_note_activity(
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+    req: HttpRequest,
+) -> impl Responder {
+    let request = inbox_request(&req, &Bytes::new());
+    match container.activity_pub_usecase.note(&params.into_inner(), &request).await {
+        Ok(NoteDocument::Published(item)) => HttpResponse::Ok()
+            .content_type("application/activity+json; charset=utf-8")
+            .body(json!(item).to_string()),
+        Ok(NoteDocument::Deleted(t)) => HttpResponse::Gone()
+            .content_type("application/activity+json; charset=utf-8")
+            .body(json!(t).to_string()),
+        Err(e) => ApiError::from(e).error_response(),
+    }
+}
+
+pub async fn get_outbox(
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+    query: Query<CollectionQuery>,
+) -> impl Responder {
+    match container.activity_pub_usecase.outbox(&params.into_inner(), query.page).await {
+        Ok(c) => collection_response(c),
+        Err(e) => ApiError::from(e).error_response(),
+    }
+}
+
+pub async fn get_followers(
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+    query: Query<CollectionQuery>,
+) -> impl Responder {
+    match container.activity_pub_usecase.followers(&params.into_inner(), query.page).await {
+        Ok(c) => collection_response(c),
+        Err(e) => ApiError::from(e).error_response(),
+    }
+}
+
+pub async fn get_following(
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+    query: Query<CollectionQuery>,
+) -> impl Responder {
+    match container.activity_pub_usecase.following(&params.into_inner(), query.page).await {
+        Ok(c) => collection_response(c),
+        Err(e) => ApiError::from(e).error_response(),
+    }
+}
+
+pub async fn get_featured(
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+) -> impl Responder {
+    match container.activity_pub_usecase.featured(&params.into_inner()).await {
+        Ok(c) => HttpResponse::Ok()
+            .content_type("application/activity+json; charset=utf-8")
+            .body(json!(c).to_string()),
+        Err(e) => ApiError::from(e).error_response(),
+    }
+}
+
+fn collection_response<T: Serialize>(collection: CollectionDocument<T>) -> HttpResponse {
+    let body = match collection {
+        CollectionDocument::Collection(c) => json!(c),
+        CollectionDocument::Page(p) => json!(p),
+    };
     HttpResponse::Ok()
         .content_type("application/activity+json; charset=utf-8")
-        .body(json!(ActivityNoteBox {
-            context: "https://www.w3.org/ns/activitystreams".to_string(),
-            summary: "outbox".to_string(),
-            r#type: "OrderedCollection".to_string(),
-            total_items: 0,
-            ordered_items: vec![],
-        }).to_string())
+        .body(body.to_string())
+}
+
+fn inbox_request(req: &HttpRequest, body: &Bytes) -> InboxRequest {
+    let headers = req.headers().iter()
+        .filter_map(|(k, v)| v.to_str().ok().map(|v| (k.as_str().to_lowercase(), v.to_string())))
+        .collect();
+    InboxRequest {
+        method: req.method().to_string(),
+        path: match req.uri().path_and_query() {
+            Some(p) => p.to_string(),
+            None => req.path().to_string(),
+        },
+        headers,
+        body: String::from_utf8_lossy(body).to_string(),
+    }
+}
+
+#[derive(Deserialize)]
+pub struct CollectionQuery {
+    page: Option<u64>,
 }
 
 #[derive(Deserialize)]
@@ -0,0 +1,52 @@
+use std::sync::Arc;
+use actix_web::web::{Data, Json};
+use serde::{Deserialize, Serialize};
+use crate::app::container::Container;
+use crate::domain::instance::instance_health::InstanceHealth;
+use crate::presentation::errors::api::ApiError;
+use crate::presentation::extractors::admin_claim::AdminClaim;
+
+pub async fn list_instances(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+) -> Result<Json<InstanceListResponse>, ApiError> {
+    let usecase = &container.instance_management_usecase;
+    let instances = usecase.list().await?;
+    Ok(Json(InstanceListResponse::from(instances)))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct InstanceResponse {
+    pub host: String,
+    pub consecutive_failures: i32,
+    pub last_success_at: Option<String>,
+    pub last_failure_at: Option<String>,
+    pub unreachable_since: Option<String>,
+    pub suspended: bool,
+}
+
+impl From<(InstanceHealth, bool)> for InstanceResponse {
+    fn from((health, suspended): (InstanceHealth, bool)) -> Self {
+        InstanceResponse {
+            host: health.host,
+            consecutive_failures: health.consecutive_failures,
+            last_success_at: health.last_success_at.map(|d| d.to_rfc3339()),
+            last_failure_at: health.last_failure_at.map(|d| d.to_rfc3339()),
+            unreachable_since: health.unreachable_since.map(|d| d.to_rfc3339()),
+            suspended,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct InstanceListResponse {
+    pub instances: Vec<InstanceResponse>,
+}
+
+impl From<Vec<(InstanceHealth, bool)>> for InstanceListResponse {
+    fn from(value: Vec<(InstanceHealth, bool)>) -> Self {
+        InstanceListResponse {
+            instances: value.into_iter().map(|i| i.into()).collect(),
+        }
+    }
+}
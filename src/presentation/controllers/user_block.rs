@@ -0,0 +1,77 @@
+use std::sync::Arc;
+use actix_web::web::{Data, Json, Path};
+use serde::{Deserialize, Serialize};
+use crate::app::container::Container;
+use crate::domain::block::block::Block;
+use crate::presentation::errors::api::ApiError;
+use crate::presentation::extractors::admin_claim::AdminClaim;
+
+pub async fn create_user_block(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+    post_data: Json<CreateUserBlockRequest>,
+) -> Result<Json<UserBlockResponse>, ApiError> {
+    let usecase = &container.user_block_usecase;
+    let block = usecase.block(&params.into_inner(), &post_data.actor).await?;
+    Ok(Json(block.into()))
+}
+
+pub async fn list_user_blocks(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+) -> Result<Json<UserBlockListResponse>, ApiError> {
+    let usecase = &container.user_block_usecase;
+    let blocks = usecase.list(&params.into_inner()).await?;
+    Ok(Json(UserBlockListResponse::from(blocks)))
+}
+
+pub async fn delete_user_block(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<(String, i32)>,
+) -> Result<String, ApiError> {
+    let usecase = &container.user_block_usecase;
+    let (user_id, block_id) = params.into_inner();
+    usecase.unblock(&user_id, block_id).await?;
+    Ok("ok".to_string())
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateUserBlockRequest {
+    // e.g. "https://other.example/users/someone"
+    pub actor: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserBlockResponse {
+    pub id: i32,
+    pub user_id: String,
+    pub actor: String,
+    pub created_at: String,
+}
+
+impl From<Block> for UserBlockResponse {
+    fn from(value: Block) -> Self {
+        UserBlockResponse {
+            id: value.id,
+            user_id: value.user_id,
+            actor: value.actor,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserBlockListResponse {
+    pub blocks: Vec<UserBlockResponse>,
+}
+
+impl From<Vec<Block>> for UserBlockListResponse {
+    fn from(value: Vec<Block>) -> Self {
+        UserBlockListResponse {
+            blocks: value.into_iter().map(|b| b.into()).collect(),
+        }
+    }
+}
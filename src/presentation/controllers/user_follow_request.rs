@@ -0,0 +1,71 @@
+use std::sync::Arc;
+use actix_web::web::{Data, Json, Path};
+use serde::{Deserialize, Serialize};
+use crate::app::container::Container;
+use crate::domain::follow_request::follow_request::FollowRequest;
+use crate::presentation::errors::api::ApiError;
+use crate::presentation::extractors::admin_claim::AdminClaim;
+
+pub async fn list_user_follow_requests(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+) -> Result<Json<UserFollowRequestListResponse>, ApiError> {
+    let usecase = &container.user_follow_request_usecase;
+    let requests = usecase.list(&params.into_inner()).await?;
+    Ok(Json(UserFollowRequestListResponse::from(requests)))
+}
+
+pub async fn accept_user_follow_request(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<(String, i32)>,
+) -> Result<String, ApiError> {
+    let usecase = &container.user_follow_request_usecase;
+    let (user_id, request_id) = params.into_inner();
+    usecase.accept(&user_id, request_id).await?;
+    Ok("ok".to_string())
+}
+
+pub async fn reject_user_follow_request(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<(String, i32)>,
+) -> Result<String, ApiError> {
+    let usecase = &container.user_follow_request_usecase;
+    let (user_id, request_id) = params.into_inner();
+    usecase.reject(&user_id, request_id).await?;
+    Ok("ok".to_string())
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserFollowRequestResponse {
+    pub id: i32,
+    pub user_id: String,
+    pub actor: String,
+    pub created_at: String,
+}
+
+impl From<FollowRequest> for UserFollowRequestResponse {
+    fn from(value: FollowRequest) -> Self {
+        UserFollowRequestResponse {
+            id: value.id,
+            user_id: value.user_id,
+            actor: value.actor,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserFollowRequestListResponse {
+    pub follow_requests: Vec<UserFollowRequestResponse>,
+}
+
+impl From<Vec<FollowRequest>> for UserFollowRequestListResponse {
+    fn from(value: Vec<FollowRequest>) -> Self {
+        UserFollowRequestListResponse {
+            follow_requests: value.into_iter().map(|r| r.into()).collect(),
+        }
+    }
+}
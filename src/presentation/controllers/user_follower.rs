@@ -0,0 +1,23 @@
+use std::sync::Arc;
+use actix_web::web::{Data, Json, Path};
+use serde::{Deserialize, Serialize};
+use crate::app::container::Container;
+use crate::presentation::errors::api::ApiError;
+use crate::presentation::extractors::admin_claim::AdminClaim;
+
+pub async fn remove_user_follower(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+    post_data: Json<RemoveUserFollowerRequest>,
+) -> Result<String, ApiError> {
+    let usecase = &container.user_follower_usecase;
+    usecase.remove(&params.into_inner(), &post_data.actor).await?;
+    Ok("ok".to_string())
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct RemoveUserFollowerRequest {
+    // e.g. "https://other.example/users/someone"
+    pub actor: String,
+}
@@ -0,0 +1,120 @@
+use std::sync::Arc;
+use actix_web::web::{Data, Json, Path, Query};
+use serde::{Deserialize, Serialize};
+use crate::app::container::Container;
+use crate::domain::following::following::{Following, FollowingStatus};
+use crate::presentation::errors::api::ApiError;
+use crate::presentation::extractors::admin_claim::AdminClaim;
+
+pub async fn create_user_following(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+    post_data: Json<CreateUserFollowingRequest>,
+) -> Result<Json<UserFollowingResponse>, ApiError> {
+    let usecase = &container.user_following_usecase;
+    let following = usecase.follow(&params.into_inner(), &post_data.account).await?;
+    Ok(Json(following.into()))
+}
+
+pub async fn list_user_followings(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+    queries: Query<UserFollowingListQuery>,
+) -> Result<Json<UserFollowingListResponse>, ApiError> {
+    let usecase = &container.user_following_usecase;
+    let followings = usecase.list(&params.into_inner(), queries.status.map(|s| s.into())).await?;
+    Ok(Json(UserFollowingListResponse::from(followings)))
+}
+
+pub async fn get_user_following(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<(String, i32)>,
+) -> Result<Json<UserFollowingResponse>, ApiError> {
+    let usecase = &container.user_following_usecase;
+    let (user_id, following_id) = params.into_inner();
+    let following = usecase.get(&user_id, following_id).await?;
+    Ok(Json(following.into()))
+}
+
+pub async fn delete_user_following(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<(String, i32)>,
+) -> Result<String, ApiError> {
+    let usecase = &container.user_following_usecase;
+    let (user_id, following_id) = params.into_inner();
+    usecase.unfollow(&user_id, following_id).await?;
+    Ok("ok".to_string())
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct CreateUserFollowingRequest {
+    // e.g. "someone@other.example"
+    pub account: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserFollowingResponse {
+    pub id: i32,
+    pub user_id: String,
+    pub actor: String,
+    pub status: String,
+    pub created_at: String,
+}
+
+impl From<Following> for UserFollowingResponse {
+    fn from(value: Following) -> Self {
+        UserFollowingResponse {
+            id: value.id,
+            user_id: value.user_id,
+            actor: value.actor,
+            status: match value.status {
+                FollowingStatus::PENDING => "pending",
+                FollowingStatus::ACCEPTED => "accepted",
+                FollowingStatus::REJECTED => "rejected",
+                FollowingStatus::UNKNOWN => "unknown",
+            }.to_string(),
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserFollowingListQuery {
+    // all statuses if omitted
+    pub status: Option<FollowingStatusParam>,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FollowingStatusParam {
+    Pending,
+    Accepted,
+    Rejected,
+}
+
+impl From<FollowingStatusParam> for FollowingStatus {
+    fn from(value: FollowingStatusParam) -> Self {
+        match value {
+            FollowingStatusParam::Pending => FollowingStatus::PENDING,
+            FollowingStatusParam::Accepted => FollowingStatus::ACCEPTED,
+            FollowingStatusParam::Rejected => FollowingStatus::REJECTED,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserFollowingListResponse {
+    pub followings: Vec<UserFollowingResponse>,
+}
+
+impl From<Vec<Following>> for UserFollowingListResponse {
+    fn from(value: Vec<Following>) -> Self {
+        UserFollowingListResponse {
+            followings: value.into_iter().map(|f| f.into()).collect(),
+        }
+    }
+}
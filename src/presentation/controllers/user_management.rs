@@ -2,7 +2,7 @@ use std::sync::Arc;
 use actix_web::web::{Data, Json, Path};
 use serde::{Deserialize, Serialize};
 use crate::app::container::Container;
-use crate::domain::user::user::User;
+use crate::domain::user::user::{ProfileField, User};
 use crate::presentation::errors::api::ApiError;
 use crate::presentation::extractors::admin_claim::AdminClaim;
 use crate::usecase::user_management::{CreateUserParams, UpdateUserParams};
@@ -50,6 +50,16 @@ pub async fn update_user(
     Ok(Json(user.into()))
 }
 
+pub async fn rotate_user_key(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+) -> Result<Json<UserResponse>, ApiError> {
+    let usecase = &container.user_management_usecase;
+    let user = usecase.rotate_key(&params.into_inner()).await?;
+    Ok(Json(user.into()))
+}
+
 pub async fn delete_user(
     _: AdminClaim,
     container: Data<Arc<Container>>,
@@ -65,6 +75,12 @@ pub struct UserResponse {
     pub id: String,
     pub username: String,
     pub display_name: String,
+    pub hide_collections: bool,
+    pub locked: bool,
+    pub summary: String,
+    pub avatar_url: Option<String>,
+    pub header_url: Option<String>,
+    pub fields: Vec<ProfileFieldPayload>,
 }
 
 impl From<User> for UserResponse {
@@ -73,6 +89,12 @@ impl From<User> for UserResponse {
             id: value.id,
             username: value.username,
             display_name: value.display_name,
+            hide_collections: value.hide_collections,
+            locked: value.locked,
+            summary: value.summary,
+            avatar_url: value.avatar_url,
+            header_url: value.header_url,
+            fields: value.fields.into_iter().map(|f| f.into()).collect(),
         }
     }
 }
@@ -94,6 +116,11 @@ impl From<Vec<User>> for UserListResponse {
 pub struct CreateUserRequest {
     pub username: String,
     pub display_name: String,
+    pub locked: Option<bool>,
+    pub summary: Option<String>,
+    pub avatar_url: Option<String>,
+    pub header_url: Option<String>,
+    pub fields: Option<Vec<ProfileFieldPayload>>,
 }
 
 impl Into<CreateUserParams> for CreateUserRequest {
@@ -101,6 +128,11 @@ impl Into<CreateUserParams> for CreateUserRequest {
         CreateUserParams {
             username: self.username,
             display_name: self.display_name,
+            locked: self.locked.unwrap_or(false),
+            summary: self.summary.unwrap_or_default(),
+            avatar_url: self.avatar_url.filter(|u| !u.is_empty()),
+            header_url: self.header_url.filter(|u| !u.is_empty()),
+            fields: self.fields.unwrap_or_default().into_iter().map(|f| f.into()).collect(),
         }
     }
 }
@@ -109,6 +141,12 @@ impl Into<CreateUserParams> for CreateUserRequest {
 pub struct UpdateUserRequest {
     pub username: String,
     pub display_name: String,
+    pub hide_collections: Option<bool>,
+    pub locked: Option<bool>,
+    pub summary: Option<String>,
+    pub avatar_url: Option<String>,
+    pub header_url: Option<String>,
+    pub fields: Option<Vec<ProfileFieldPayload>>,
 }
 
 impl Into<UpdateUserParams> for UpdateUserRequest {
@@ -116,6 +154,36 @@ impl Into<UpdateUserParams> for UpdateUserRequest {
         UpdateUserParams {
             username: self.username,
             display_name: self.display_name,
+            hide_collections: self.hide_collections,
+            locked: self.locked,
+            summary: self.summary,
+            avatar_url: self.avatar_url,
+            header_url: self.header_url,
+            fields: self.fields.map(|l| l.into_iter().map(|f| f.into()).collect()),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ProfileFieldPayload {
+    pub name: String,
+    pub value: String,
+}
+
+impl From<ProfileField> for ProfileFieldPayload {
+    fn from(value: ProfileField) -> Self {
+        ProfileFieldPayload {
+            name: value.name,
+            value: value.value,
+        }
+    }
+}
+
+impl From<ProfileFieldPayload> for ProfileField {
+    fn from(value: ProfileFieldPayload) -> Self {
+        ProfileField {
+            name: value.name,
+            value: value.value,
         }
     }
 }
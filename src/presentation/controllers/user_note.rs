@@ -2,7 +2,8 @@ use std::sync::Arc;
 use actix_web::web::{Data, Json, Path, Query};
 use serde::{Deserialize, Serialize};
 use crate::app::container::Container;
-use crate::domain::note::note::Note;
+use crate::domain::note::note::{Note, NoteVisibility};
+use crate::domain::note::note_history::NoteHistory;
 use crate::domain::note::paging::{NotesPage, NotesPagingParams};
 use crate::presentation::errors::api::ApiError;
 use crate::presentation::extractors::admin_claim::AdminClaim;
@@ -15,7 +16,8 @@ pub async fn create_user_note(
 ) -> Result<Json<UserNoteResponse>, ApiError> {
     let usecase = &container.user_note_usecase;
     let data = &post_data.into_inner();
-    let note = usecase.create(&params.into_inner(), &data.content).await?;
+    let visibility = data.visibility.unwrap_or(NoteVisibilityParam::Public).into();
+    let note = usecase.create(&params.into_inner(), &data.content, visibility).await?;
     Ok(Json(note.into()))
 }
 
@@ -41,6 +43,51 @@ pub async fn get_user_note(
     Ok(Json(note.into()))
 }
 
+pub async fn update_user_note(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<(String, String)>,
+    post_data: Json<UpdateUserNoteRequest>,
+) -> Result<Json<UserNoteResponse>, ApiError> {
+    let usecase = &container.user_note_usecase;
+    let (user_id, note_id) = params.into_inner();
+    let note = usecase.update(&user_id, &note_id, &post_data.into_inner().content).await?;
+    Ok(Json(note.into()))
+}
+
+pub async fn pin_user_note(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<(String, String)>,
+) -> Result<Json<UserNoteResponse>, ApiError> {
+    let usecase = &container.user_note_usecase;
+    let (user_id, note_id) = params.into_inner();
+    let note = usecase.pin(&user_id, &note_id).await?;
+    Ok(Json(note.into()))
+}
+
+pub async fn unpin_user_note(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<(String, String)>,
+) -> Result<Json<UserNoteResponse>, ApiError> {
+    let usecase = &container.user_note_usecase;
+    let (user_id, note_id) = params.into_inner();
+    let note = usecase.unpin(&user_id, &note_id).await?;
+    Ok(Json(note.into()))
+}
+
+pub async fn list_user_note_history(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<(String, String)>,
+) -> Result<Json<UserNoteHistoryListResponse>, ApiError> {
+    let usecase = &container.user_note_usecase;
+    let (user_id, note_id) = params.into_inner();
+    let history = usecase.history(&user_id, &note_id).await?;
+    Ok(Json(UserNoteHistoryListResponse::from(history)))
+}
+
 pub async fn delete_user_note(
     _: AdminClaim,
     container: Data<Arc<Container>>,
@@ -57,7 +104,10 @@ pub struct UserNoteResponse {
     pub id: String,
     pub user_id: String,
     pub content: String,
+    pub visibility: String,
+    pub pinned: bool,
     pub created_at: String,
+    pub updated_at: String,
 }
 
 impl From<Note> for UserNoteResponse {
@@ -66,7 +116,15 @@ impl From<Note> for UserNoteResponse {
             id: value.id,
             user_id: value.user_id,
             content: value.content,
+            visibility: match value.visibility {
+                NoteVisibility::PUBLIC => "public",
+                NoteVisibility::UNLISTED => "unlisted",
+                NoteVisibility::FOLLOWERS => "followers",
+                NoteVisibility::UNKNOWN => "unknown",
+            }.to_string(),
+            pinned: value.pinned_at.is_some(),
             created_at: value.created_at.to_rfc3339(),
+            updated_at: value.updated_at.to_rfc3339(),
         }
     }
 }
@@ -74,6 +132,59 @@ impl From<Note> for UserNoteResponse {
 #[derive(Serialize, Deserialize)]
 pub struct CreateUserNoteRequest {
     pub content: String,
+    // public if omitted
+    pub visibility: Option<NoteVisibilityParam>,
+}
+
+#[derive(Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NoteVisibilityParam {
+    Public,
+    Unlisted,
+    Followers,
+}
+
+impl From<NoteVisibilityParam> for NoteVisibility {
+    fn from(value: NoteVisibilityParam) -> Self {
+        match value {
+            NoteVisibilityParam::Public => NoteVisibility::PUBLIC,
+            NoteVisibilityParam::Unlisted => NoteVisibility::UNLISTED,
+            NoteVisibilityParam::Followers => NoteVisibility::FOLLOWERS,
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UpdateUserNoteRequest {
+    pub content: String,
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserNoteHistoryResponse {
+    pub content: String,
+    pub created_at: String,
+}
+
+impl From<NoteHistory> for UserNoteHistoryResponse {
+    fn from(value: NoteHistory) -> Self {
+        UserNoteHistoryResponse {
+            content: value.content,
+            created_at: value.created_at.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct UserNoteHistoryListResponse {
+    pub history: Vec<UserNoteHistoryResponse>,
+}
+
+impl From<Vec<NoteHistory>> for UserNoteHistoryListResponse {
+    fn from(value: Vec<NoteHistory>) -> Self {
+        UserNoteHistoryListResponse {
+            history: value.into_iter().map(|h| h.into()).collect(),
+        }
+    }
 }
 
 #[derive(Serialize, Deserialize)]
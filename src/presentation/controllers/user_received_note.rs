@@ -0,0 +1,59 @@
+use std::sync::Arc;
+use actix_web::web::{Data, Json, Path, Query};
+use serde::{Deserialize, Serialize};
+use crate::app::container::Container;
+use crate::domain::remote_note::remote_note::{RemoteNote, RemoteNotesPage};
+use crate::presentation::controllers::user_note::UserNoteListQuery;
+use crate::presentation::errors::api::ApiError;
+use crate::presentation::extractors::admin_claim::AdminClaim;
+
+pub async fn list_received_notes(
+    _: AdminClaim,
+    container: Data<Arc<Container>>,
+    params: Path<String>,
+    queries: Query<UserNoteListQuery>,
+) -> Result<Json<ReceivedNoteListResponse>, ApiError> {
+    let usecase = &container.user_received_note_usecase;
+    let notes = usecase.list(&params.into_inner(), &queries.into_inner().into()).await?;
+    Ok(Json(ReceivedNoteListResponse::from(notes)))
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReceivedNoteResponse {
+    pub id: i32,
+    pub object_id: String,
+    pub actor: String,
+    pub content: String,
+    pub summary: Option<String>,
+    pub in_reply_to: Option<String>,
+    pub published: String,
+}
+
+impl From<RemoteNote> for ReceivedNoteResponse {
+    fn from(value: RemoteNote) -> Self {
+        ReceivedNoteResponse {
+            id: value.id,
+            object_id: value.object_id,
+            actor: value.actor,
+            content: value.content,
+            summary: value.summary,
+            in_reply_to: value.in_reply_to,
+            published: value.published.to_rfc3339(),
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize)]
+pub struct ReceivedNoteListResponse {
+    pub total: u64,
+    pub notes: Vec<ReceivedNoteResponse>,
+}
+
+impl From<RemoteNotesPage> for ReceivedNoteListResponse {
+    fn from(value: RemoteNotesPage) -> Self {
+        ReceivedNoteListResponse {
+            total: value.total,
+            notes: value.notes.into_iter().map(|n| n.into()).collect(),
+        }
+    }
+}
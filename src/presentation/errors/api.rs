@@ -22,8 +22,20 @@ impl actix_web::ResponseError for ApiError {
     fn error_response(&self) -> HttpResponse<BoxBody> {
         match self.0.get_code() {
             CommonErrorCode::UserDoesNotExists => HttpResponse::NotFound().body(self.0.get_message()),
+            CommonErrorCode::UserGone => HttpResponse::Gone().body(self.0.get_message()),
             CommonErrorCode::UsernameAlreadyExists => HttpResponse::BadRequest().body(self.0.get_message()),
+            CommonErrorCode::TooManyProfileFields => HttpResponse::BadRequest().body(self.0.get_message()),
             CommonErrorCode::NoteDoesNotExists => HttpResponse::NotFound().body(self.0.get_message()),
+            CommonErrorCode::NoteNotPinnable => HttpResponse::BadRequest().body(self.0.get_message()),
+            CommonErrorCode::RemoteActorDoesNotExists => HttpResponse::NotFound().body(self.0.get_message()),
+            CommonErrorCode::FollowerDoesNotExists => HttpResponse::NotFound().body(self.0.get_message()),
+            CommonErrorCode::FollowingDoesNotExists => HttpResponse::NotFound().body(self.0.get_message()),
+            CommonErrorCode::FollowRequestDoesNotExists => HttpResponse::NotFound().body(self.0.get_message()),
+            CommonErrorCode::BlockDoesNotExists => HttpResponse::NotFound().body(self.0.get_message()),
+            CommonErrorCode::AlreadyFollowing => HttpResponse::BadRequest().body(self.0.get_message()),
+            CommonErrorCode::AlreadyBlocking => HttpResponse::BadRequest().body(self.0.get_message()),
+            CommonErrorCode::InvalidSignature => HttpResponse::Unauthorized().body(self.0.get_message()),
+            CommonErrorCode::InvalidActivity => HttpResponse::BadRequest().body(self.0.get_message()),
             CommonErrorCode::DBError => HttpResponse::InternalServerError().body(""),
             CommonErrorCode::UnexpectedError => HttpResponse::InternalServerError().body(""),
         }
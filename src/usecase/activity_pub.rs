@@ -1,31 +1,80 @@
+use std::cmp::Reverse;
 use std::sync::Arc;
-use crate::domain::activity_pub::activity_pub::{InboxActivity, NodeInfo, NodeInfoLinks, Person, WebFinger};
+use chrono::{DateTime, Duration, Utc};
+use serde::Serialize;
+use crate::domain::activity_pub::activity_pub::{ActivityNoteItem, ActivityObject, NodeInfo, NodeInfoLinks, OrderedCollection, OrderedCollectionPage, Person, Tombstone, WebFinger};
 use crate::domain::activity_pub::activity_pub_service::ActivityPubService;
+use crate::domain::activity_pub::activity_streams::{ActivityType, InboxActivity, ObjectRef, ObjectType};
+use crate::domain::activity_pub::http_signature::InboxRequest;
 use crate::domain::app_config::AppConfig;
+use crate::domain::block::block_repository::BlockRepository;
 use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::follow_request::follow_request::FollowRequest;
+use crate::domain::follow_request::follow_request_repository::FollowRequestRepository;
 use crate::domain::follower::follower::Follower;
 use crate::domain::follower::follower_repository::FollowerRepository;
+use crate::domain::following::following::FollowingStatus;
+use crate::domain::following::following_repository::FollowingRepository;
+use crate::domain::instance::instance_service::InstanceService;
+use crate::domain::note::note::{Note, NoteStatus};
+use crate::domain::note::note_repository::NoteRepository;
+use crate::domain::note::paging::NotesPagingParams;
+use crate::domain::processed_activity::processed_activity::ProcessedActivity;
+use crate::domain::processed_activity::processed_activity_repository::ProcessedActivityRepository;
+use crate::domain::remote_actor::remote_actor::RemoteActor;
+use crate::domain::remote_note::remote_note::{sanitize, RemoteNote};
+use crate::domain::remote_note::remote_note_repository::RemoteNoteRepository;
+use crate::domain::user::user::User;
 use crate::domain::user::user_repository::UserRepository;
 
+const COLLECTION_PAGE_SIZE: u64 = 12;
+const PRUNE_INTERVAL: std::time::Duration = std::time::Duration::from_secs(3600);
+
 pub struct ActivityPubUseCase {
     app_url: String,
+    signature_clock_skew: i64,
+    processed_activity_retention: i64,
     activity_pub_service: Arc<ActivityPubService>,
     user_repository: Arc<dyn UserRepository>,
     follower_repository: Arc<dyn FollowerRepository>,
+    following_repository: Arc<dyn FollowingRepository>,
+    follow_request_repository: Arc<dyn FollowRequestRepository>,
+    block_repository: Arc<dyn BlockRepository>,
+    note_repository: Arc<dyn NoteRepository>,
+    remote_note_repository: Arc<dyn RemoteNoteRepository>,
+    processed_activity_repository: Arc<dyn ProcessedActivityRepository>,
+    instance_service: Arc<InstanceService>,
 }
 
 impl ActivityPubUseCase {
+    #[allow(clippy::too_many_arguments)]
     pub fn new(
         app_config: Arc<AppConfig>,
         activity_pub_service: Arc<ActivityPubService>,
         user_repository: Arc<dyn UserRepository>,
         follower_repository: Arc<dyn FollowerRepository>,
+        following_repository: Arc<dyn FollowingRepository>,
+        follow_request_repository: Arc<dyn FollowRequestRepository>,
+        block_repository: Arc<dyn BlockRepository>,
+        note_repository: Arc<dyn NoteRepository>,
+        remote_note_repository: Arc<dyn RemoteNoteRepository>,
+        processed_activity_repository: Arc<dyn ProcessedActivityRepository>,
+        instance_service: Arc<InstanceService>,
     ) -> Self {
         ActivityPubUseCase {
             app_url: app_config.app_url.clone(),
+            signature_clock_skew: app_config.signature_clock_skew,
+            processed_activity_retention: app_config.processed_activity_retention,
             activity_pub_service,
             user_repository,
             follower_repository,
+            following_repository,
+            follow_request_repository,
+            block_repository,
+            note_repository,
+            remote_note_repository,
+            processed_activity_repository,
+            instance_service,
         }
     }
 
@@ -33,7 +82,7 @@ impl ActivityPubUseCase {
         self.activity_pub_service.host_meta(&self.app_url).await
     }
 
-    pub async fn web_finger(&self, params: &WebFingerParams) -> Result<WebFinger, ()> {
+    pub async fn web_finger(&self, params: &WebFingerParams) -> Result<WebFinger, CommonError> {
         self.activity_pub_service.web_finger(&params.resource, &self.app_url).await
     }
 
@@ -53,32 +102,468 @@ impl ActivityPubUseCase {
         self.activity_pub_service.get_redirect_url_to_username(user_id, &self.app_url).await
     }
 
-    pub async fn process_inbox_activity(&self, user_id: &String, activity: &InboxActivity) -> Result<(), CommonError> {
+    pub async fn followers(&self, user_id: &str, page: Option<u64>) -> Result<CollectionDocument<String>, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let mut followers = self.follower_repository.list(&user.id).await?;
+        followers.sort_by_key(|f| Reverse(f.created_at));
+        let actors = followers.into_iter().map(|f| f.actor).collect();
+        Ok(self.actor_collection(&user, "followers", actors, page))
+    }
+
+    pub async fn following(&self, user_id: &str, page: Option<u64>) -> Result<CollectionDocument<String>, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let mut followings = self.following_repository.list(&user.id).await?;
+        followings.retain(|f| f.status == FollowingStatus::ACCEPTED);
+        followings.sort_by_key(|f| Reverse(f.created_at));
+        let actors = followings.into_iter().map(|f| f.actor).collect();
+        Ok(self.actor_collection(&user, "following", actors, page))
+    }
+
+    pub async fn featured(&self, user_id: &str) -> Result<OrderedCollection<ActivityObject>, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let notes = self.note_repository.list_pinned(&user.id).await?;
+        Ok(self.activity_pub_service.featured_collection(&user, &notes, &self.app_url))
+    }
+
+    // create activities of the notes readable without being a follower
+    pub async fn outbox(&self, user_id: &str, page: Option<u64>) -> Result<CollectionDocument<ActivityNoteItem>, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let id = format!("{}users/{}/outbox", self.app_url, user.id);
+        let total = self.note_repository.count_public(&user.id).await? as usize;
+        match page {
+            Some(p) if p > 0 => {
+                let items = match page_offset(p, total) {
+                    Some(offset) => {
+                        let paging_params = NotesPagingParams {
+                            offset: Some(offset),
+                            limit: Some(COLLECTION_PAGE_SIZE),
+                        };
+                        self.note_repository.list_public(&user.id, &paging_params).await?
+                            .notes.iter()
+                            .map(|n| self.activity_pub_service.note_item(n, &self.app_url))
+                            .collect()
+                    }
+                    None => vec![],
+                };
+                Ok(CollectionDocument::Page(self.activity_pub_service.collection_page(&id, p, COLLECTION_PAGE_SIZE, total, items)))
+            }
+            _ => Ok(CollectionDocument::Collection(self.activity_pub_service.collection(&id, total, false))),
+        }
+    }
+
+    // hidden members are not listed, pages are answered with the collection itself
+    fn actor_collection(&self, user: &User, name: &str, actors: Vec<String>, page: Option<u64>) -> CollectionDocument<String> {
+        let id = format!("{}users/{}/{}", self.app_url, user.id, name);
+        match page {
+            Some(p) if p > 0 && !user.hide_collections => {
+                let items = match page_offset(p, actors.len()) {
+                    Some(offset) => actors.iter()
+                        .skip(offset as usize)
+                        .take(COLLECTION_PAGE_SIZE as usize)
+                        .cloned()
+                        .collect(),
+                    None => vec![],
+                };
+                CollectionDocument::Page(self.activity_pub_service.collection_page(&id, p, COLLECTION_PAGE_SIZE, actors.len(), items))
+            }
+            _ => CollectionDocument::Collection(self.activity_pub_service.collection(&id, actors.len(), user.hide_collections)),
+        }
+    }
+
+    // note and its create activity, deleted notes are answered with a tombstone
+    pub async fn note(&self, note_id: &str, request: &InboxRequest) -> Result<NoteDocument, CommonError> {
+        let note = match self.note_repository.find(note_id).await? {
+            Some(n) => n,
+            None => return Err(CommonError::new(CommonErrorCode::NoteDoesNotExists)),
+        };
+        // not revealed to those who can not read it
+        if !self.can_read_note(&note, request).await? {
+            return Err(CommonError::new(CommonErrorCode::NoteDoesNotExists));
+        }
+        match note.status {
+            NoteStatus::PUBLISHED => Ok(NoteDocument::Published(Box::new(self.activity_pub_service.note_item(&note, &self.app_url)))),
+            NoteStatus::DELETED => Ok(NoteDocument::Deleted(self.activity_pub_service.note_tombstone(&note, &self.app_url))),
+            NoteStatus::UNKNOWN => Err(CommonError::new(CommonErrorCode::NoteDoesNotExists)),
+        }
+    }
+
+    // followers only notes require a fetch signed by a follower
+    async fn can_read_note(&self, note: &Note, request: &InboxRequest) -> Result<bool, CommonError> {
+        if note.visibility.is_public() {
+            return Ok(true);
+        }
+        let signer = match self.activity_pub_service.verify_fetch_request(request, self.signature_clock_skew).await {
+            Ok(a) => a,
+            Err(_) => return Ok(false),
+        };
+        Ok(self.follower_repository.find(&note.user_id, &signer.id).await?.is_some())
+    }
+
+    pub async fn receive_inbox_activity(&self, user_id: &String, request: &InboxRequest) -> Result<(), CommonError> {
+        let (actor, activity) = self.verify_inbox_activity(request).await?;
+        self.process_inbox_activity(user_id, &actor, &activity).await
+    }
+
+    pub async fn receive_shared_inbox_activity(&self, request: &InboxRequest) -> Result<(), CommonError> {
+        let (actor, activity) = self.verify_inbox_activity(request).await?;
+
+        let mut result = Ok(());
+        for user_id in self.resolve_local_recipients(&activity).await?.iter() {
+            if let Err(e) = self.process_inbox_activity(user_id, &actor, &activity).await {
+                log::warn!("Failed to process {} for {}: {}", activity.id, user_id, e.get_message());
+                result = Err(e);
+            }
+        }
+        result
+    }
+
+    async fn verify_inbox_activity(&self, request: &InboxRequest) -> Result<(RemoteActor, InboxActivity), CommonError> {
+        let signer = self.activity_pub_service.verify_inbox_request(request, self.signature_clock_skew).await?;
+
+        let activity: InboxActivity = match serde_json::from_str(&request.body) {
+            Ok(a) => a,
+            Err(e) => {
+                log::warn!("Failed to parse activity: {}", e);
+                return Err(CommonError::new(CommonErrorCode::InvalidActivity));
+            }
+        };
+
+        if activity.id.is_empty() {
+            log::warn!("Activity without id from {}", signer.id);
+            return Err(CommonError::new(CommonErrorCode::InvalidActivity));
+        }
+
+        // activity must be signed by its actor
+        if signer.id != activity.actor {
+            log::warn!("Signer {} does not match actor {}", signer.id, activity.actor);
+            return Err(CommonError::new(CommonErrorCode::InvalidSignature));
+        }
+
+        // the host is alive if it sends activities
+        self.instance_service.record_success(&signer.id).await?;
+
+        Ok((signer, activity))
+    }
+
+    // local users concerned with the activity delivered to shared inbox
+    async fn resolve_local_recipients(&self, activity: &InboxActivity) -> Result<Vec<String>, CommonError> {
+        let mut user_ids: Vec<String> = Vec::new();
+
+        // follow concerns its object only
+        if activity.r#type == ActivityType::Follow {
+            if let Some(user_id) = self.local_user_id(activity.object_id()) {
+                if self.user_repository.get(&user_id).await.is_ok() {
+                    user_ids.push(user_id);
+                }
+            }
+            return Ok(user_ids);
+        }
+
+        // addressed to local users
+        let mut addressees: Vec<&str> = activity.recipients().map(|r| r.as_str()).collect();
+        match activity.object() {
+            Some(ObjectRef::Link(uri)) => addressees.push(uri),
+            Some(ObjectRef::Activity(a)) => addressees.extend([a.actor.as_str(), a.object_id()]),
+            Some(ObjectRef::Object(o)) => addressees.extend(o.recipients().map(|r| r.as_str())),
+            None => {}
+        }
+        for uri in addressees {
+            if let Some(user_id) = self.local_user_id(uri) {
+                if !user_ids.contains(&user_id) && self.user_repository.get(&user_id).await.is_ok() {
+                    user_ids.push(user_id);
+                }
+            }
+        }
+
+        // followed by the actor, for the changes of the actor itself and its follows
+        if matches!(activity.r#type, ActivityType::Undo | ActivityType::Delete | ActivityType::Update) {
+            for f in self.follower_repository.list_by_actor(&activity.actor).await?.iter() {
+                if !user_ids.contains(&f.user_id) {
+                    user_ids.push(f.user_id.clone());
+                }
+            }
+        }
+
+        // following the actor
+        for f in self.following_repository.list_by_actor(&activity.actor).await?.iter() {
+            if f.status == FollowingStatus::ACCEPTED && !user_ids.contains(&f.user_id) {
+                user_ids.push(f.user_id.clone());
+            }
+        }
+
+        // reply to our follow request
+        if let Some(f) = self.following_repository.find_by_object(activity.object_id()).await? {
+            if !user_ids.contains(&f.user_id) {
+                user_ids.push(f.user_id.clone());
+            }
+        }
+
+        Ok(user_ids)
+    }
+
+    // https://foo.example.com/users/{user_id} -> {user_id}
+    fn local_user_id(&self, uri: &str) -> Option<String> {
+        let user_id = uri.strip_prefix(&format!("{}users/", self.app_url))?;
+        if user_id.is_empty() || user_id.contains(['/', '#', '?']) {
+            return None;
+        }
+        Some(user_id.to_string())
+    }
+
+    // remote servers retry deliveries, so the same activity is processed only once for each user
+    async fn process_inbox_activity(&self, user_id: &str, actor: &RemoteActor, activity: &InboxActivity) -> Result<(), CommonError> {
+        let processed = ProcessedActivity::new(&activity.id, user_id);
+        if !self.processed_activity_repository.add(&processed).await? {
+            log::info!("Activity {} is already processed for {}", activity.id, user_id);
+            return Ok(());
+        }
+
+        let result = self.handle_inbox_activity(user_id, actor, activity).await;
+        if result.is_err() {
+            // failed activity can be processed again by retried delivery
+            self.processed_activity_repository.delete(&activity.id, user_id).await?;
+        }
+        result
+    }
+
+    // background worker, records older than the retention are not needed to detect retried deliveries
+    pub async fn run_pruning(self: Arc<Self>) {
+        loop {
+            if let Err(e) = self.prune_processed_activities().await {
+                log::error!("Failed to prune processed activities: {}", e.get_message());
+            }
+            actix_web::rt::time::sleep(PRUNE_INTERVAL).await;
+        }
+    }
+
+    pub async fn prune_processed_activities(&self) -> Result<(), CommonError> {
+        let expired = Utc::now() - Duration::seconds(self.processed_activity_retention);
+        self.processed_activity_repository.delete_before(expired).await
+    }
+
+    async fn handle_inbox_activity(&self, user_id: &str, actor: &RemoteActor, activity: &InboxActivity) -> Result<(), CommonError> {
         let user = self.user_repository.get(user_id).await?;
 
-        match &*activity.r#type {
-            "Follow" => {
-                let follower = Follower::new(
-                    &user.id,
-                    &activity.actor,
-                    &activity.object.object,
-                    &format!("{}/inbox", activity.actor)
-                );
-                self.follower_repository.add(&follower).await?;
-                self.activity_pub_service.send_follow_accept(&user, &activity, &self.app_url).await
-            }
-            "Undo" => {
-                let followers = self.follower_repository.list(&user.id).await?;
-                for f in followers.iter() {
-                    if f.actor == activity.object.actor {
-                        self.follower_repository.delete(f.id).await?;
+        // nothing from blocked actors is accepted, including Follow
+        if self.block_repository.find(&user.id, &activity.actor).await?.is_some() {
+            log::info!("Dropped {:?} {} from {} blocked by {}", activity.r#type, activity.id, activity.actor, user.id);
+            return Ok(());
+        }
+
+        match activity.r#type {
+            ActivityType::Follow => {
+                if activity.object_id() != format!("{}users/{}", self.app_url, user.id) {
+                    log::warn!("Follow {} is not for {}", activity.id, user.id);
+                    return Err(CommonError::new(CommonErrorCode::InvalidActivity));
+                }
+                // object holds id of the Follow activity to be matched with Undo
+                match self.follower_repository.find(&user.id, &activity.actor).await? {
+                    // follow again (e.g. remote server lost the state), accept the new request
+                    Some(mut follower) => {
+                        follower.object = activity.id.clone();
+                        follower.inbox = actor.inbox.clone();
+                        self.follower_repository.update(&follower).await?;
+                    }
+                    // locked user approves the request later
+                    None if user.locked => return self.add_follow_request(&user, actor, activity).await,
+                    None => {
+                        let follower = Follower::new(
+                            &user.id,
+                            &activity.actor,
+                            &activity.id,
+                            &actor.inbox,
+                        );
+                        self.follower_repository.add(&follower).await?;
+                    }
+                }
+                self.activity_pub_service.send_follow_accept(&user, activity, &actor.inbox, &self.app_url).await
+            }
+            ActivityType::Undo => self.receive_undo(&user, activity).await,
+            ActivityType::Create => self.receive_note(&user, activity).await,
+            ActivityType::Accept => self.receive_follow_reply(&user, activity, FollowingStatus::ACCEPTED).await,
+            ActivityType::Reject => self.receive_follow_reply(&user, activity, FollowingStatus::REJECTED).await,
+            // valid but not processed, accepted so that remote servers do not retry
+            ActivityType::Add |
+            ActivityType::Announce |
+            ActivityType::Block |
+            ActivityType::Delete |
+            ActivityType::Like |
+            ActivityType::Remove |
+            ActivityType::Update |
+            ActivityType::Unknown => {
+                log::info!("Ignored {:?}: {}", activity.r#type, activity.id);
+                Ok(())
+            }
+        }
+    }
+
+    async fn add_follow_request(&self, user: &User, actor: &RemoteActor, activity: &InboxActivity) -> Result<(), CommonError> {
+        match self.follow_request_repository.find(&user.id, &activity.actor).await? {
+            // requested again, keep the latest Follow
+            Some(mut request) => {
+                request.object = activity.id.clone();
+                request.inbox = actor.inbox.clone();
+                self.follow_request_repository.update(&request).await
+            }
+            None => {
+                let request = FollowRequest::new(&user.id, &activity.actor, &activity.id, &actor.inbox);
+                self.follow_request_repository.add(&request).await
+            }
+        }
+    }
+
+    async fn receive_undo(&self, user: &User, activity: &InboxActivity) -> Result<(), CommonError> {
+        let inner = match activity.object() {
+            Some(ObjectRef::Activity(a)) => a,
+            // type is unknown when only id is given
+            Some(ObjectRef::Link(id)) => return self.undo_follow(user, activity, id, None).await,
+            Some(ObjectRef::Object(o)) => {
+                log::info!("Ignored Undo of {:?}: {}", o.r#type, o.id);
+                return Ok(());
+            }
+            None => return Err(CommonError::new(CommonErrorCode::InvalidActivity)),
+        };
+
+        // only the actor of the original activity can undo it
+        if !inner.actor.is_empty() && inner.actor != activity.actor {
+            log::warn!("{} can not undo the activity of {}", activity.actor, inner.actor);
+            return Err(CommonError::new(CommonErrorCode::InvalidActivity));
+        }
+
+        match inner.r#type {
+            ActivityType::Follow => self.undo_follow(user, activity, &inner.id, Some(inner.object_id())).await,
+            // nothing is stored for these activities
+            ActivityType::Like | ActivityType::Announce | ActivityType::Block => {
+                log::info!("Undo {:?} {}", inner.r#type, inner.id);
+                Ok(())
+            }
+            ActivityType::Accept |
+            ActivityType::Add |
+            ActivityType::Create |
+            ActivityType::Delete |
+            ActivityType::Reject |
+            ActivityType::Remove |
+            ActivityType::Undo |
+            ActivityType::Update |
+            ActivityType::Unknown => {
+                log::info!("Ignored Undo of {:?}: {}", inner.r#type, inner.id);
+                Ok(())
+            }
+        }
+    }
+
+    async fn undo_follow(&self, user: &User, activity: &InboxActivity, follow_id: &str, followee: Option<&str>) -> Result<(), CommonError> {
+        let follower = match self.follower_repository.find_by_object(&user.id, follow_id).await? {
+            Some(f) => Some(f),
+            // followers stored before follow ids were recorded
+            None if followee == Some(&*format!("{}users/{}", self.app_url, user.id)) => {
+                self.follower_repository.list_by_actor(&activity.actor).await?
+                    .into_iter()
+                    .find(|f| f.user_id == user.id && Some(&*f.object) == followee)
+            }
+            None => None,
+        };
+        match follower {
+            Some(f) if f.actor == activity.actor => self.follower_repository.delete(f.id).await,
+            Some(f) => {
+                log::warn!("{} can not undo the follow of {}", activity.actor, f.actor);
+                Err(CommonError::new(CommonErrorCode::InvalidActivity))
+            }
+            None => {
+                // withdrawn before being approved
+                if let Some(r) = self.follow_request_repository.find(&user.id, &activity.actor).await? {
+                    if r.object == follow_id {
+                        return self.follow_request_repository.delete(r.id).await;
                     }
                 }
+                log::info!("Follow {} is already undone", follow_id);
                 Ok(())
             }
-            _ => Err(CommonError::new(CommonErrorCode::UnexpectedError))
         }
     }
+
+    // Accept{Follow} or Reject{Follow} for the follow request sent by local user
+    async fn receive_follow_reply(&self, user: &User, activity: &InboxActivity, status: FollowingStatus) -> Result<(), CommonError> {
+        let follow_id = match activity.object() {
+            Some(ObjectRef::Link(id)) => id,
+            Some(ObjectRef::Activity(a)) if a.r#type == ActivityType::Follow => &a.id,
+            Some(o) => {
+                log::info!("Ignored {:?} of {}", activity.r#type, o.id());
+                return Ok(());
+            }
+            None => return Err(CommonError::new(CommonErrorCode::InvalidActivity)),
+        };
+
+        let mut following = match self.following_repository.find_by_object(follow_id).await? {
+            Some(f) if f.user_id == user.id => f,
+            _ => {
+                log::info!("Follow request {} does not exists", follow_id);
+                return Ok(());
+            }
+        };
+
+        // only the followee can reply
+        if following.actor != activity.actor {
+            log::warn!("{} is not the target of follow request {}", activity.actor, following.object);
+            return Err(CommonError::new(CommonErrorCode::InvalidActivity));
+        }
+
+        following.status = status;
+        following.updated_at = Utc::now();
+        self.following_repository.update(&following).await
+    }
+
+    async fn receive_note(&self, user: &User, activity: &InboxActivity) -> Result<(), CommonError> {
+        let object = match activity.object() {
+            Some(ObjectRef::Object(o)) => o,
+            Some(o) => {
+                log::info!("Ignored Create of {}", o.id());
+                return Ok(());
+            }
+            None => return Err(CommonError::new(CommonErrorCode::InvalidActivity)),
+        };
+        if object.r#type != ObjectType::Note {
+            log::info!("Ignored Create of {:?}: {}", object.r#type, object.id);
+            return Ok(());
+        }
+
+        // note must be created by the actor
+        if object.id.is_empty() || !object.attributed_to.contains(&activity.actor) {
+            log::warn!("Note {} is not attributed to {}", object.id, activity.actor);
+            return Err(CommonError::new(CommonErrorCode::InvalidActivity));
+        }
+
+        let published = match object.published.as_ref().map(|p| DateTime::parse_from_rfc3339(p)) {
+            Some(Ok(p)) => p.with_timezone(&Utc),
+            _ => Utc::now(),
+        };
+        let mut note = RemoteNote::new(
+            &user.id,
+            &object.id,
+            &activity.actor,
+            object.content.as_deref().unwrap_or_default(),
+            published,
+        );
+        note.summary = object.summary.as_deref().map(sanitize).filter(|s| !s.is_empty());
+        note.in_reply_to = object.in_reply_to.clone();
+
+        self.remote_note_repository.add(&note).await
+    }
+}
+
+// offset of the page, None if the page is beyond the last item
+fn page_offset(page: u64, total_items: usize) -> Option<u64> {
+    (page - 1).checked_mul(COLLECTION_PAGE_SIZE).filter(|o| *o < total_items as u64)
+}
+
+pub enum CollectionDocument<T: Serialize> {
+    Collection(OrderedCollection<T>),
+    Page(OrderedCollectionPage<T>),
+}
+
+pub enum NoteDocument {
+    Published(Box<ActivityNoteItem>),
+    Deleted(Tombstone),
 }
 
 pub struct WebFingerParams {
@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use crate::domain::error::CommonError;
+use crate::domain::instance::instance_health::InstanceHealth;
+use crate::domain::instance::instance_service::InstanceService;
+
+pub struct InstanceManagementUseCase {
+    instance_service: Arc<InstanceService>,
+}
+
+impl InstanceManagementUseCase {
+    pub fn new(instance_service: Arc<InstanceService>) -> Self {
+        InstanceManagementUseCase {
+            instance_service,
+        }
+    }
+
+    // health of each remote host with whether deliveries to it are suspended
+    pub async fn list(&self) -> Result<Vec<(InstanceHealth, bool)>, CommonError> {
+        let instances = self.instance_service.list().await?;
+        Ok(instances.into_iter()
+            .map(|h| {
+                let suspended = self.instance_service.is_suspended(&h);
+                (h, suspended)
+            })
+            .collect())
+    }
+}
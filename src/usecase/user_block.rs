@@ -0,0 +1,114 @@
+use std::sync::Arc;
+use url::Url;
+use crate::domain::activity_pub::activity_pub_service::ActivityPubService;
+use crate::domain::app_config::AppConfig;
+use crate::domain::block::block::Block;
+use crate::domain::block::block_repository::BlockRepository;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::follow_request::follow_request_repository::FollowRequestRepository;
+use crate::domain::follower::follower_repository::FollowerRepository;
+use crate::domain::following::following_repository::FollowingRepository;
+use crate::domain::id_generator::IDGenerator;
+use crate::domain::remote_actor::remote_actor_service::RemoteActorService;
+use crate::domain::user::user_repository::UserRepository;
+
+pub struct UserBlockUseCase {
+    app_config: Arc<AppConfig>,
+    block_repository: Arc<dyn BlockRepository>,
+    follower_repository: Arc<dyn FollowerRepository>,
+    following_repository: Arc<dyn FollowingRepository>,
+    follow_request_repository: Arc<dyn FollowRequestRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    remote_actor_service: Arc<RemoteActorService>,
+    activity_pub_service: Arc<ActivityPubService>,
+}
+
+impl UserBlockUseCase {
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        app_config: Arc<AppConfig>,
+        block_repository: Arc<dyn BlockRepository>,
+        follower_repository: Arc<dyn FollowerRepository>,
+        following_repository: Arc<dyn FollowingRepository>,
+        follow_request_repository: Arc<dyn FollowRequestRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        remote_actor_service: Arc<RemoteActorService>,
+        activity_pub_service: Arc<ActivityPubService>,
+    ) -> Self {
+        UserBlockUseCase {
+            app_config,
+            block_repository,
+            follower_repository,
+            following_repository,
+            follow_request_repository,
+            user_repository,
+            remote_actor_service,
+            activity_pub_service,
+        }
+    }
+
+    pub async fn list(&self, user_id: &str) -> Result<Vec<Block>, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        self.block_repository.list(&user.id).await
+    }
+
+    // the block takes effect locally even if the remote server does not answer
+    pub async fn block(&self, user_id: &str, actor_id: &str) -> Result<Block, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        if Url::parse(actor_id).is_err() {
+            return Err(CommonError::new(CommonErrorCode::RemoteActorDoesNotExists));
+        }
+        if self.block_repository.find(&user.id, actor_id).await?.is_some() {
+            return Err(CommonError::new(CommonErrorCode::AlreadyBlocking));
+        }
+
+        // relations in both directions are dropped, the remote server does the same on Block
+        if let Some(f) = self.follower_repository.find(&user.id, actor_id).await? {
+            self.follower_repository.delete(f.id).await?;
+        }
+        if let Some(r) = self.follow_request_repository.find(&user.id, actor_id).await? {
+            self.follow_request_repository.delete(r.id).await?;
+        }
+        if let Some(f) = self.following_repository.find(&user.id, actor_id).await? {
+            self.following_repository.delete(f.id).await?;
+        }
+
+        let object = format!("{}activities/{}", self.app_config.app_url, IDGenerator::generate(16));
+        let block = self.block_repository.add(&Block::new(&user.id, actor_id, &object)).await?;
+
+        match self.inbox_of(&block.actor).await {
+            Some(inbox) => {
+                if let Err(e) = self.activity_pub_service.send_block(&user, &block, &inbox, &self.app_config.app_url).await {
+                    log::warn!("Failed to send Block {}: {}", block.object, e.get_message());
+                }
+            }
+            None => log::warn!("Block {} is not sent since {} can not be resolved", block.object, block.actor),
+        }
+
+        Ok(block)
+    }
+
+    pub async fn unblock(&self, user_id: &str, block_id: i32) -> Result<(), CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let block = self.block_repository.get(&user.id, block_id).await?;
+
+        match self.inbox_of(&block.actor).await {
+            Some(inbox) => {
+                if let Err(e) = self.activity_pub_service.send_undo_block(&user, &block, &inbox, &self.app_config.app_url).await {
+                    log::warn!("Failed to send Undo of {}: {}", block.object, e.get_message());
+                }
+            }
+            None => log::warn!("Undo of {} is not sent since {} can not be resolved", block.object, block.actor),
+        }
+
+        self.block_repository.delete(block.id).await
+    }
+
+    // the remote actor may be gone, the cached inbox is used regardless of its age
+    async fn inbox_of(&self, actor_id: &str) -> Option<String> {
+        match self.remote_actor_service.find_cached(actor_id).await {
+            Ok(Some(a)) => Some(a.inbox),
+            _ => self.remote_actor_service.resolve(actor_id).await.ok().map(|a| a.inbox),
+        }
+    }
+}
@@ -0,0 +1,68 @@
+use std::sync::Arc;
+use crate::domain::activity_pub::activity_pub_service::ActivityPubService;
+use crate::domain::app_config::AppConfig;
+use crate::domain::error::CommonError;
+use crate::domain::follow_request::follow_request::FollowRequest;
+use crate::domain::follow_request::follow_request_repository::FollowRequestRepository;
+use crate::domain::follower::follower_repository::FollowerRepository;
+use crate::domain::user::user_repository::UserRepository;
+
+pub struct UserFollowRequestUseCase {
+    app_config: Arc<AppConfig>,
+    follow_request_repository: Arc<dyn FollowRequestRepository>,
+    follower_repository: Arc<dyn FollowerRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    activity_pub_service: Arc<ActivityPubService>,
+}
+
+impl UserFollowRequestUseCase {
+    pub fn new(
+        app_config: Arc<AppConfig>,
+        follow_request_repository: Arc<dyn FollowRequestRepository>,
+        follower_repository: Arc<dyn FollowerRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        activity_pub_service: Arc<ActivityPubService>,
+    ) -> Self {
+        UserFollowRequestUseCase {
+            app_config,
+            follow_request_repository,
+            follower_repository,
+            user_repository,
+            activity_pub_service,
+        }
+    }
+
+    pub async fn list(&self, user_id: &str) -> Result<Vec<FollowRequest>, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        self.follow_request_repository.list(&user.id).await
+    }
+
+    pub async fn accept(&self, user_id: &str, request_id: i32) -> Result<(), CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let request = self.follow_request_repository.get(&user.id, request_id).await?;
+
+        match self.follower_repository.find(&user.id, &request.actor).await? {
+            Some(mut follower) => {
+                follower.object = request.object.clone();
+                follower.inbox = request.inbox.clone();
+                self.follower_repository.update(&follower).await?;
+            }
+            None => self.follower_repository.add(&request.to_follower()).await?,
+        }
+        self.follow_request_repository.delete(request.id).await?;
+
+        self.activity_pub_service.send_follow_reply(
+            &user, "Accept", &request.object, &request.actor, &request.inbox, &self.app_config.app_url,
+        ).await
+    }
+
+    pub async fn reject(&self, user_id: &str, request_id: i32) -> Result<(), CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let request = self.follow_request_repository.get(&user.id, request_id).await?;
+        self.follow_request_repository.delete(request.id).await?;
+
+        self.activity_pub_service.send_follow_reply(
+            &user, "Reject", &request.object, &request.actor, &request.inbox, &self.app_config.app_url,
+        ).await
+    }
+}
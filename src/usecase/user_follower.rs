@@ -0,0 +1,43 @@
+use std::sync::Arc;
+use crate::domain::activity_pub::activity_pub_service::ActivityPubService;
+use crate::domain::app_config::AppConfig;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::follower::follower_repository::FollowerRepository;
+use crate::domain::user::user_repository::UserRepository;
+
+pub struct UserFollowerUseCase {
+    app_config: Arc<AppConfig>,
+    follower_repository: Arc<dyn FollowerRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    activity_pub_service: Arc<ActivityPubService>,
+}
+
+impl UserFollowerUseCase {
+    pub fn new(
+        app_config: Arc<AppConfig>,
+        follower_repository: Arc<dyn FollowerRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        activity_pub_service: Arc<ActivityPubService>,
+    ) -> Self {
+        UserFollowerUseCase {
+            app_config,
+            follower_repository,
+            user_repository,
+            activity_pub_service,
+        }
+    }
+
+    // the actor can follow again, Reject{Follow} tells the remote server to drop the relation
+    pub async fn remove(&self, user_id: &str, actor: &str) -> Result<(), CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let follower = match self.follower_repository.find(&user.id, actor).await? {
+            Some(f) => f,
+            None => return Err(CommonError::new(CommonErrorCode::FollowerDoesNotExists)),
+        };
+        self.follower_repository.delete(follower.id).await?;
+
+        self.activity_pub_service.send_follow_reply(
+            &user, "Reject", &follower.object, &follower.actor, &follower.inbox, &self.app_config.app_url,
+        ).await
+    }
+}
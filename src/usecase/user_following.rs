@@ -0,0 +1,92 @@
+use std::cmp::Reverse;
+use std::sync::Arc;
+use crate::domain::activity_pub::activity_pub_service::ActivityPubService;
+use crate::domain::app_config::AppConfig;
+use crate::domain::error::{CommonError, CommonErrorCode};
+use crate::domain::following::following::{Following, FollowingStatus};
+use crate::domain::following::following_repository::FollowingRepository;
+use crate::domain::id_generator::IDGenerator;
+use crate::domain::remote_actor::remote_actor_service::RemoteActorService;
+use crate::domain::user::user_repository::UserRepository;
+
+pub struct UserFollowingUseCase {
+    app_config: Arc<AppConfig>,
+    following_repository: Arc<dyn FollowingRepository>,
+    user_repository: Arc<dyn UserRepository>,
+    remote_actor_service: Arc<RemoteActorService>,
+    activity_pub_service: Arc<ActivityPubService>,
+}
+
+impl UserFollowingUseCase {
+    pub fn new(
+        app_config: Arc<AppConfig>,
+        following_repository: Arc<dyn FollowingRepository>,
+        user_repository: Arc<dyn UserRepository>,
+        remote_actor_service: Arc<RemoteActorService>,
+        activity_pub_service: Arc<ActivityPubService>,
+    ) -> Self {
+        UserFollowingUseCase {
+            app_config,
+            following_repository,
+            user_repository,
+            remote_actor_service,
+            activity_pub_service,
+        }
+    }
+
+    pub async fn follow(&self, user_id: &str, account: &str) -> Result<Following, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let actor = self.remote_actor_service.resolve_account(account).await?;
+
+        match self.following_repository.find(&user.id, &actor.id).await? {
+            // rejected follow can be requested again
+            Some(f) if f.status == FollowingStatus::REJECTED => self.following_repository.delete(f.id).await?,
+            Some(_) => return Err(CommonError::new(CommonErrorCode::AlreadyFollowing)),
+            None => {}
+        }
+
+        let object = format!("{}activities/{}", self.app_config.app_url, IDGenerator::generate(16));
+        let following = self.following_repository.add(&Following::new(&user.id, &actor.id, &object)).await?;
+        self.activity_pub_service.send_follow(&user, &following, &actor.inbox, &self.app_config.app_url).await?;
+
+        Ok(following)
+    }
+
+    // all followings if status is omitted, newest first
+    pub async fn list(&self, user_id: &str, status: Option<FollowingStatus>) -> Result<Vec<Following>, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let mut followings = self.following_repository.list(&user.id).await?;
+        if let Some(status) = status {
+            followings.retain(|f| f.status == status);
+        }
+        followings.sort_by_key(|f| Reverse(f.created_at));
+        Ok(followings)
+    }
+
+    pub async fn get(&self, user_id: &str, following_id: i32) -> Result<Following, CommonError> {
+        self.following_repository
+            .get(user_id, following_id)
+            .await
+    }
+
+    pub async fn unfollow(&self, user_id: &str, following_id: i32) -> Result<(), CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let following = self.following_repository.get(&user.id, following_id).await?;
+
+        // the remote actor may be gone, the cached inbox is used regardless of its age
+        let inbox = match self.remote_actor_service.find_cached(&following.actor).await {
+            Ok(Some(a)) => Some(a.inbox),
+            _ => self.remote_actor_service.resolve(&following.actor).await.ok().map(|a| a.inbox),
+        };
+        match inbox {
+            Some(inbox) => {
+                if let Err(e) = self.activity_pub_service.send_undo_follow(&user, &following, &inbox, &self.app_config.app_url).await {
+                    log::warn!("Failed to send Undo of {}: {}", following.object, e.get_message());
+                }
+            }
+            None => log::warn!("Undo of {} is not sent since {} can not be resolved", following.object, following.actor),
+        }
+
+        self.following_repository.delete(following.id).await
+    }
+}
@@ -1,23 +1,44 @@
 use std::sync::Arc;
 use chrono::Utc;
+use crate::domain::activity_pub::activity_pub_service::ActivityPubService;
+use crate::domain::app_config::AppConfig;
 use crate::domain::error::{CommonError, CommonErrorCode};
-use crate::domain::user::user::User;
+use crate::domain::follower::follower_repository::FollowerRepository;
+use crate::domain::following::following_repository::FollowingRepository;
+use crate::domain::user::user::{MAX_PROFILE_FIELDS, ProfileField, User};
 use crate::domain::user::user_repository::UserRepository;
 use crate::domain::user::user_service::UserService;
+use crate::domain::user::user_tombstone::UserTombstone;
+use crate::domain::user::user_tombstone_repository::UserTombstoneRepository;
 
 pub struct UserManagementUseCase {
+    app_config: Arc<AppConfig>,
     user_repository: Arc<dyn UserRepository>,
+    user_tombstone_repository: Arc<dyn UserTombstoneRepository>,
+    follower_repository: Arc<dyn FollowerRepository>,
+    following_repository: Arc<dyn FollowingRepository>,
     user_service: Arc<UserService>,
+    activity_pub_service: Arc<ActivityPubService>,
 }
 
 impl UserManagementUseCase {
     pub fn new(
+        app_config: Arc<AppConfig>,
         user_repository: Arc<dyn UserRepository>,
+        user_tombstone_repository: Arc<dyn UserTombstoneRepository>,
+        follower_repository: Arc<dyn FollowerRepository>,
+        following_repository: Arc<dyn FollowingRepository>,
         user_service: Arc<UserService>,
+        activity_pub_service: Arc<ActivityPubService>,
     ) -> Self {
         UserManagementUseCase {
+            app_config,
             user_repository,
+            user_tombstone_repository,
+            follower_repository,
+            following_repository,
             user_service,
+            activity_pub_service,
         }
     }
 
@@ -31,7 +52,16 @@ impl UserManagementUseCase {
             return Err(CommonError::new(CommonErrorCode::UsernameAlreadyExists));
         }
 
-        let new_user = User::new(&params.username, &params.display_name);
+        if params.fields.len() > MAX_PROFILE_FIELDS {
+            return Err(CommonError::new(CommonErrorCode::TooManyProfileFields));
+        }
+
+        let mut new_user = User::new(&params.username, &params.display_name);
+        new_user.locked = params.locked;
+        new_user.summary = params.summary.clone();
+        new_user.avatar_url = params.avatar_url.clone();
+        new_user.header_url = params.header_url.clone();
+        new_user.fields = params.fields.clone();
         self.user_repository
             .add(&new_user)
             .await
@@ -68,6 +98,28 @@ impl UserManagementUseCase {
         // update
         user.username = params.username.clone();
         user.display_name = params.display_name.clone();
+        if let Some(h) = params.hide_collections {
+            user.hide_collections = h;
+        }
+        if let Some(l) = params.locked {
+            user.locked = l;
+        }
+        if let Some(s) = &params.summary {
+            user.summary = s.clone();
+        }
+        // an empty url removes the image
+        if let Some(u) = &params.avatar_url {
+            user.avatar_url = (!u.is_empty()).then(|| u.clone());
+        }
+        if let Some(u) = &params.header_url {
+            user.header_url = (!u.is_empty()).then(|| u.clone());
+        }
+        if let Some(f) = &params.fields {
+            if f.len() > MAX_PROFILE_FIELDS {
+                return Err(CommonError::new(CommonErrorCode::TooManyProfileFields));
+            }
+            user.fields = f.clone();
+        }
         user.updated_at = Utc::now();
         match self.user_repository.update(&user).await {
             Ok(_) => {}
@@ -75,22 +127,58 @@ impl UserManagementUseCase {
         }
 
         // return updated user
-        self.user_repository.get(user_id).await
+        let user = self.user_repository.get(user_id).await?;
+        self.broadcast_profile(&user).await?;
+        Ok(user)
+    }
+
+    pub async fn rotate_key(&self, user_id: &str) -> Result<User, CommonError> {
+        let mut user = self.user_repository.get(user_id).await?;
+        user.rotate_key(Utc::now());
+        self.user_repository.update(&user).await?;
+
+        let user = self.user_repository.get(user_id).await?;
+        self.broadcast_profile(&user).await?;
+        Ok(user)
+    }
+
+    // followers refresh the cached actor
+    async fn broadcast_profile(&self, user: &User) -> Result<(), CommonError> {
+        let recipients = self.follower_repository.list(&user.id).await?;
+        self.activity_pub_service.send_update_person(user, recipients, &self.app_config.app_url).await
     }
 
     pub async fn delete(&self, user_id: &str) -> Result<(), CommonError> {
-        self.user_repository
-            .delete(user_id)
-            .await
+        let user = self.user_repository.get(user_id).await?;
+
+        // signed before the key is deleted
+        let followers = self.follower_repository.list(&user.id).await?;
+        let followings = self.following_repository.list(&user.id).await?;
+        self.activity_pub_service.send_delete_person(&user, followers, followings, &self.app_config.app_url).await?;
+
+        self.user_repository.delete(&user.id).await?;
+        self.user_tombstone_repository.add(&UserTombstone::new(&user)).await
     }
 }
 
 pub struct CreateUserParams {
     pub username: String,
     pub display_name: String,
+    pub locked: bool,
+    pub summary: String,
+    pub avatar_url: Option<String>,
+    pub header_url: Option<String>,
+    pub fields: Vec<ProfileField>,
 }
 
 pub struct UpdateUserParams {
     pub username: String,
     pub display_name: String,
+    // unchanged when omitted
+    pub hide_collections: Option<bool>,
+    pub locked: Option<bool>,
+    pub summary: Option<String>,
+    pub avatar_url: Option<String>,
+    pub header_url: Option<String>,
+    pub fields: Option<Vec<ProfileField>>,
 }
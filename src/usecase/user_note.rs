@@ -1,9 +1,13 @@
 use std::sync::Arc;
+use chrono::Utc;
 use crate::domain::activity_pub::activity_pub_service::ActivityPubService;
 use crate::domain::app_config::AppConfig;
-use crate::domain::error::CommonError;
+use crate::domain::error::{CommonError, CommonErrorCode};
 use crate::domain::follower::follower_repository::FollowerRepository;
-use crate::domain::note::note::{Note, NoteStatus};
+use crate::domain::note::note::{Note, NoteStatus, NoteVisibility};
+use crate::domain::note::note_history::NoteHistory;
+use crate::domain::note::note_history_repository::NoteHistoryRepository;
+use crate::domain::note::note_recipient_repository::NoteRecipientRepository;
 use crate::domain::note::note_repository::NoteRepository;
 use crate::domain::note::paging::{NotesPage, NotesPagingParams};
 use crate::domain::user::user_repository::UserRepository;
@@ -11,6 +15,8 @@ use crate::domain::user::user_repository::UserRepository;
 pub struct UserNoteUseCase {
     app_config: Arc<AppConfig>,
     note_repository: Arc<dyn NoteRepository>,
+    note_history_repository: Arc<dyn NoteHistoryRepository>,
+    note_recipient_repository: Arc<dyn NoteRecipientRepository>,
     user_repository: Arc<dyn UserRepository>,
     follower_repository: Arc<dyn FollowerRepository>,
     activity_pub_service: Arc<ActivityPubService>,
@@ -20,6 +26,8 @@ impl UserNoteUseCase {
     pub fn new(
         app_config: Arc<AppConfig>,
         note_repository: Arc<dyn NoteRepository>,
+        note_history_repository: Arc<dyn NoteHistoryRepository>,
+        note_recipient_repository: Arc<dyn NoteRecipientRepository>,
         user_repository: Arc<dyn UserRepository>,
         follower_repository: Arc<dyn FollowerRepository>,
         activity_pub_service: Arc<ActivityPubService>,
@@ -27,20 +35,23 @@ impl UserNoteUseCase {
         UserNoteUseCase {
             app_config,
             note_repository,
+            note_history_repository,
+            note_recipient_repository,
             user_repository,
             follower_repository,
             activity_pub_service,
         }
     }
 
-    pub async fn create(&self, user_id: &String, content: &String) -> Result<Note, CommonError> {
+    pub async fn create(&self, user_id: &String, content: &String, visibility: NoteVisibility) -> Result<Note, CommonError> {
         let user = self.user_repository.get(user_id).await?;
 
-        let new_note = Note::new(&user.id, content);
+        let new_note = Note::new(&user.id, content, visibility);
         self.note_repository.add(&new_note).await?;
 
         let recipients = self.follower_repository.list(&user.id).await?;
-        self.activity_pub_service.send_note(&user, &new_note, recipients, &self.app_config.app_url).await?;
+        let inboxes = self.activity_pub_service.send_note(&user, &new_note, recipients, &self.app_config.app_url).await?;
+        self.note_recipient_repository.add(&new_note.id, &inboxes).await?;
 
         Ok(new_note)
     }
@@ -57,6 +68,63 @@ impl UserNoteUseCase {
             .await
     }
 
+    pub async fn update(&self, user_id: &String, note_id: &String, content: &str) -> Result<Note, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let mut note = self.note_repository.get(user_id, note_id).await?;
+
+        self.note_history_repository.add(&NoteHistory::new(&note)).await?;
+        note.edit(content, Utc::now());
+        self.note_repository.update(&note).await?;
+
+        let recipients = self.follower_repository.list(&user.id).await?;
+        let inboxes = self.activity_pub_service.send_update_note(&user, &note, recipients, &self.app_config.app_url).await?;
+        self.note_recipient_repository.add(&note.id, &inboxes).await?;
+
+        Ok(note)
+    }
+
+    // featured on the profile, pinning twice does nothing
+    pub async fn pin(&self, user_id: &String, note_id: &String) -> Result<Note, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let mut note = self.note_repository.get(user_id, note_id).await?;
+        if !note.visibility.is_public() {
+            return Err(CommonError::new(CommonErrorCode::NoteNotPinnable));
+        }
+        if note.pinned_at.is_some() {
+            return Ok(note);
+        }
+
+        note.pin(Utc::now());
+        self.note_repository.update(&note).await?;
+
+        let recipients = self.follower_repository.list(&user.id).await?;
+        self.activity_pub_service.send_add_featured(&user, &note, recipients, &self.app_config.app_url).await?;
+
+        Ok(note)
+    }
+
+    pub async fn unpin(&self, user_id: &String, note_id: &String) -> Result<Note, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        let mut note = self.note_repository.get(user_id, note_id).await?;
+        if note.pinned_at.is_none() {
+            return Ok(note);
+        }
+
+        note.unpin();
+        self.note_repository.update(&note).await?;
+
+        let recipients = self.follower_repository.list(&user.id).await?;
+        self.activity_pub_service.send_remove_featured(&user, &note, recipients, &self.app_config.app_url).await?;
+
+        Ok(note)
+    }
+
+    // previous revisions, oldest first
+    pub async fn history(&self, user_id: &String, note_id: &String) -> Result<Vec<NoteHistory>, CommonError> {
+        let note = self.note_repository.get(user_id, note_id).await?;
+        self.note_history_repository.list(&note.id).await
+    }
+
     pub async fn delete(&self, user_id: &String, note_id: &String) -> Result<(), CommonError> {
         let mut note = match self.note_repository.get(user_id, note_id).await {
             Ok(n) => n,
@@ -64,9 +132,14 @@ impl UserNoteUseCase {
         };
 
         note.status = NoteStatus::DELETED;
-        match self.note_repository.update(&note).await {
-            Ok(_) => Ok(()),
-            Err(e) => Err(e)
-        }
+        note.updated_at = Utc::now();
+        self.note_repository.update(&note).await?;
+
+        // everyone who received the note removes their copy, current followers may have fetched it
+        let user = self.user_repository.get(user_id).await?;
+        let recipients = self.follower_repository.list(&user.id).await?;
+        let delivered_to = self.note_recipient_repository.list(&note.id).await?;
+        self.activity_pub_service.send_delete_note(&user, &note, recipients, delivered_to, &self.app_config.app_url).await?;
+        self.note_recipient_repository.delete(&note.id).await
     }
 }
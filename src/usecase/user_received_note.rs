@@ -0,0 +1,27 @@
+use std::sync::Arc;
+use crate::domain::error::CommonError;
+use crate::domain::note::paging::NotesPagingParams;
+use crate::domain::remote_note::remote_note::RemoteNotesPage;
+use crate::domain::remote_note::remote_note_repository::RemoteNoteRepository;
+use crate::domain::user::user_repository::UserRepository;
+
+pub struct UserReceivedNoteUseCase {
+    remote_note_repository: Arc<dyn RemoteNoteRepository>,
+    user_repository: Arc<dyn UserRepository>,
+}
+
+impl UserReceivedNoteUseCase {
+    pub fn new(remote_note_repository: Arc<dyn RemoteNoteRepository>, user_repository: Arc<dyn UserRepository>) -> Self {
+        UserReceivedNoteUseCase {
+            remote_note_repository,
+            user_repository,
+        }
+    }
+
+    pub async fn list(&self, user_id: &str, paging_params: &NotesPagingParams) -> Result<RemoteNotesPage, CommonError> {
+        let user = self.user_repository.get(user_id).await?;
+        self.remote_note_repository
+            .list(&user.id, paging_params)
+            .await
+    }
+}
@@ -1,6 +1,10 @@
 mod api {
     mod test_echo_controller;
+    mod test_user_following_controller;
     mod test_user_note_controller;
     mod test_user_management_controller;
     mod test_activity_pub_controller;
+    mod test_activity_pub_inbox;
+    mod test_instance_management_controller;
+    mod test_activity_pub_collections;
 }
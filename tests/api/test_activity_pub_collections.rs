@@ -0,0 +1,278 @@
+#[cfg(test)]
+mod test_activity_pub_collections {
+    use std::env;
+    use actix_web::test;
+    use chrono::{Duration, Utc};
+    use sea_orm::{ActiveModelTrait, Database};
+    use sea_orm::ActiveValue::Set;
+    use serde_json::Value;
+    use gekidan::app::factory::create_app;
+    use gekidan::infrastructure::databases::entities::{follower, following};
+    use gekidan::presentation::controllers::user_management::UserResponse;
+    use gekidan::presentation::controllers::user_note::{UserNoteListResponse, UserNoteResponse};
+    use migrations::{Migrator, MigratorTrait};
+
+    #[actix_web::test]
+    async fn test() {
+        let _ = env_logger::try_init();
+
+        env::set_var("ENV", "test");
+        let app = test::init_service(create_app()).await;
+
+        // setup database
+        let db = Database::connect(dotenv::var("DATABASE_URL").unwrap()).await.unwrap();
+        let _ = Migrator::fresh(&db).await;
+
+        // auth header
+        let api_key = ("x-admin-api-key", dotenv::var("ADMIN_API_KEY").unwrap());
+
+        // add user
+        let res = test::TestRequest::post().uri("/admin/users")
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"username": "hoge", "display_name": "Hoge One"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserResponse = test::read_body_json(res).await;
+        assert!(!body.hide_collections);
+        let uid = body.id;
+        let followers_url = format!("http://test.example.com/users/{}/followers", uid);
+        let following_url = format!("http://test.example.com/users/{}/following", uid);
+
+        // 13 followers, newest first
+        let now = Utc::now();
+        for n in 0..13 {
+            follower::ActiveModel {
+                user_id: Set(uid.clone()),
+                actor: Set(format!("https://remote.example.com/users/foo{}", n)),
+                object: Set(format!("https://remote.example.com/follows/{}", n)),
+                inbox: Set(format!("https://remote.example.com/users/foo{}/inbox", n)),
+                created_at: Set((now - Duration::seconds(n)).to_rfc3339()),
+                ..Default::default()
+            }.insert(&db).await.unwrap();
+        }
+
+        // accepted and pending followings
+        for (n, status) in [(1, 2), (2, 1)] {
+            following::ActiveModel {
+                user_id: Set(uid.clone()),
+                actor: Set(format!("https://remote.example.com/users/bar{}", n)),
+                object: Set(format!("http://test.example.com/follows/{}", n)),
+                status: Set(status),
+                created_at: Set(now.to_rfc3339()),
+                updated_at: Set(now.to_rfc3339()),
+                ..Default::default()
+            }.insert(&db).await.unwrap();
+        }
+
+        // actor links the collections
+        let res = test::TestRequest::get().uri("/@hoge").send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["followers"], followers_url);
+        assert_eq!(body["following"], following_url);
+        assert_eq!(body["featured"], format!("http://test.example.com/users/{}/collections/featured", uid));
+
+        // followers
+        let res = test::TestRequest::get().uri(&format!("/users/{}/followers", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        assert_eq!(res.headers().get("Content-Type").unwrap().to_str().unwrap(), "application/activity+json; charset=utf-8");
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["type"], "OrderedCollection");
+        assert_eq!(body["id"], followers_url);
+        assert_eq!(body["totalItems"], 13);
+        assert_eq!(body["first"], format!("{}?page=1", followers_url));
+
+        // first page
+        let res = test::TestRequest::get().uri(&format!("/users/{}/followers?page=1", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["type"], "OrderedCollectionPage");
+        assert_eq!(body["partOf"], followers_url);
+        assert_eq!(body["totalItems"], 13);
+        assert_eq!(body["orderedItems"].as_array().unwrap().len(), 12);
+        assert_eq!(body["orderedItems"][0], "https://remote.example.com/users/foo0");
+        assert_eq!(body["next"], format!("{}?page=2", followers_url));
+        assert!(body["prev"].is_null());
+
+        // last page
+        let res = test::TestRequest::get().uri(&format!("/users/{}/followers?page=2", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["orderedItems"].as_array().unwrap().len(), 1);
+        assert_eq!(body["orderedItems"][0], "https://remote.example.com/users/foo12");
+        assert!(body["next"].is_null());
+        assert_eq!(body["prev"], format!("{}?page=1", followers_url));
+
+        // beyond the last page
+        let res = test::TestRequest::get().uri(&format!("/users/{}/followers?page=18446744073709551615", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["totalItems"], 13);
+        assert!(body["orderedItems"].as_array().unwrap().is_empty());
+        assert!(body["next"].is_null());
+
+        // following lists accepted ones only
+        let res = test::TestRequest::get().uri(&format!("/users/{}/following?page=1", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["partOf"], following_url);
+        assert_eq!(body["totalItems"], 1);
+        assert_eq!(body["orderedItems"][0], "https://remote.example.com/users/bar1");
+
+        // notes of the user and another one
+        let mut nids = vec![];
+        for n in 0..13 {
+            let res = test::TestRequest::post().uri(&format!("/users/{}/notes", uid))
+                .append_header(api_key.clone())
+                .append_header(("Content-Type", "application/json"))
+                .set_payload(format!(r#"{{"content": "note {}"}}"#, n))
+                .send_request(&app)
+                .await;
+            assert!(res.status().is_success());
+            let body: UserNoteResponse = test::read_body_json(res).await;
+            nids.push(body.id);
+        }
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes", uid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "for followers", "visibility": "followers"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let res = test::TestRequest::post().uri("/admin/users")
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"username": "fuga", "display_name": "Fuga One"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let other: UserResponse = test::read_body_json(res).await;
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes", other.id))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "other"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+
+        // total counts notes of the user only
+        let res = test::TestRequest::get().uri(&format!("/users/{}/notes", other.id))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserNoteListResponse = test::read_body_json(res).await;
+        assert_eq!(body.total, 1);
+
+        // outbox
+        let outbox_url = format!("http://test.example.com/users/{}/outbox", uid);
+        let res = test::TestRequest::get().uri(&format!("/users/{}/outbox", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["type"], "OrderedCollection");
+        assert_eq!(body["id"], outbox_url);
+        assert_eq!(body["totalItems"], 13);
+        assert_eq!(body["first"], format!("{}?page=1", outbox_url));
+
+        // first page has the newest create activities
+        let res = test::TestRequest::get().uri(&format!("/users/{}/outbox?page=1", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["type"], "OrderedCollectionPage");
+        assert_eq!(body["partOf"], outbox_url);
+        assert_eq!(body["orderedItems"].as_array().unwrap().len(), 12);
+        assert_eq!(body["next"], format!("{}?page=2", outbox_url));
+        let item = &body["orderedItems"][0];
+        assert_eq!(item["type"], "Create");
+        assert_eq!(item["id"], format!("http://test.example.com/notes/{}/activity", nids[12]));
+        assert_eq!(item["object"]["content"], "note 12");
+        assert_eq!(item["published"], item["object"]["published"]);
+
+        // followers only note is not listed
+        let res = test::TestRequest::get().uri(&format!("/users/{}/outbox?page=2", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["orderedItems"].as_array().unwrap().len(), 1);
+        assert_eq!(body["orderedItems"][0]["object"]["content"], "note 0");
+        assert!(body["next"].is_null());
+        assert_eq!(body["prev"], format!("{}?page=1", outbox_url));
+
+        // beyond the last page
+        let res = test::TestRequest::get().uri(&format!("/users/{}/outbox?page=18446744073709551615", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["totalItems"], 13);
+        assert!(body["orderedItems"].as_array().unwrap().is_empty());
+        assert!(body["next"].is_null());
+
+        // edited note keeps the original published
+        let res = test::TestRequest::put().uri(&format!("/users/{}/notes/{}", uid, nids[12]))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "note 12 edited"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let res = test::TestRequest::get().uri(&format!("/users/{}/outbox?page=1", uid)).send_request(&app).await;
+        let edited: Value = test::read_body_json(res).await;
+        let edited = &edited["orderedItems"][0];
+        assert_eq!(edited["object"]["content"], "note 12 edited");
+        assert_eq!(edited["published"], item["published"]);
+        assert!(edited["object"]["updated"].is_string());
+
+        // deleted note is not listed
+        let res = test::TestRequest::delete().uri(&format!("/users/{}/notes/{}", uid, nids[12]))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let res = test::TestRequest::get().uri(&format!("/users/{}/outbox", uid)).send_request(&app).await;
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["totalItems"], 12);
+
+        // hide members
+        let res = test::TestRequest::put().uri(&format!("/admin/users/{}", uid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"username": "hoge", "display_name": "Hoge One", "hide_collections": true}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserResponse = test::read_body_json(res).await;
+        assert!(body.hide_collections);
+
+        // only the number is shown
+        let res = test::TestRequest::get().uri(&format!("/users/{}/followers", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["totalItems"], 13);
+        assert!(body["first"].is_null());
+
+        // pages are not served
+        let res = test::TestRequest::get().uri(&format!("/users/{}/following?page=1", uid)).send_request(&app).await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["type"], "OrderedCollection");
+        assert_eq!(body["totalItems"], 1);
+        assert!(body["orderedItems"].is_null());
+
+        // update without the flag keeps it
+        let res = test::TestRequest::put().uri(&format!("/admin/users/{}", uid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"username": "hoge", "display_name": "Hoge Two"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserResponse = test::read_body_json(res).await;
+        assert!(body.hide_collections);
+
+        // unknown user (fail)
+        let res = test::TestRequest::get().uri("/users/unknown/followers").send_request(&app).await;
+        assert_eq!(res.status().as_u16(), 404);
+        let res = test::TestRequest::get().uri("/users/unknown/outbox").send_request(&app).await;
+        assert_eq!(res.status().as_u16(), 404);
+    }
+}
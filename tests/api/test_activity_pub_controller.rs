@@ -90,5 +90,34 @@ mod test_activity_pub_controller {
         assert!(res.status().is_success());
         let body: Actor = test::read_body_json(res).await;
         assert_eq!(body.id, format!("{}users/{}", app_url, uid));
+
+        // inbox without signature (fail)
+        let follow = r#"{"type": "Follow", "id": "http://127.0.0.1:1/follows/1", "actor": "http://127.0.0.1:1/users/foo", "object": "http://test.example.com/users/foo"}"#;
+        let res = test::TestRequest::post().uri(&format!("/users/{}/inbox", uid))
+            .append_header(("Content-Type", "application/activity+json"))
+            .set_payload(follow)
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 401);
+
+        // inbox with unverifiable signature (fail)
+        let res = test::TestRequest::post().uri(&format!("/users/{}/inbox", uid))
+            .append_header(("Content-Type", "application/activity+json"))
+            .append_header((
+                "Signature",
+                r#"keyId="http://127.0.0.1:1/users/foo#main-key",algorithm="rsa-sha256",headers="(request-target) host date digest",signature="c2lnbg==""#
+            ))
+            .set_payload(follow)
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 401);
+
+        // shared inbox without signature (fail)
+        let res = test::TestRequest::post().uri("/inbox")
+            .append_header(("Content-Type", "application/activity+json"))
+            .set_payload(follow)
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 401);
     }
 }
@@ -0,0 +1,634 @@
+#[cfg(test)]
+mod test_activity_pub_inbox {
+    use std::env;
+    use std::time::SystemTime;
+    use actix_web::http::header::Date;
+    use actix_web::test;
+    use chrono::{Duration, Utc};
+    use sea_orm::{ActiveModelTrait, ColumnTrait, Database, EntityTrait, QueryFilter};
+    use sea_orm::ActiveValue::Set;
+    use gekidan::app::container::Container;
+    use gekidan::app::factory::create_app;
+    use gekidan::domain::activity_pub::http_signature::http_digest_header;
+    use gekidan::domain::processed_activity::processed_activity::ProcessedActivity;
+    use gekidan::domain::processed_activity::processed_activity_repository::ProcessedActivityRepository;
+    use gekidan::domain::user::user::User;
+    use gekidan::infrastructure::databases::entities::{delivery_job, follower, following, processed_activity, remote_actor, remote_note};
+    use gekidan::infrastructure::repositories::processed_activity::ProcessedActivitySeaORMRepository;
+    use gekidan::presentation::controllers::instance_management::InstanceListResponse;
+    use gekidan::presentation::controllers::user_block::{UserBlockListResponse, UserBlockResponse};
+    use gekidan::presentation::controllers::user_follow_request::UserFollowRequestListResponse;
+    use gekidan::presentation::controllers::user_following::UserFollowingResponse;
+    use gekidan::presentation::controllers::user_management::UserResponse;
+    use migrations::{Migrator, MigratorTrait};
+
+    const REMOTE_ACTOR: &str = "http://127.0.0.1:1/users/remote";
+
+    // sign request as the remote actor
+    fn signed_request(uri: &str, body: &str, signer: &User) -> test::TestRequest {
+        let date = Date(SystemTime::now().into()).to_string();
+        let digest = http_digest_header(body);
+        let signing_string = format!(
+            "(request-target): post {}\nhost: test.example.com\ndate: {}\ndigest: {}",
+            uri, date, digest
+        );
+        test::TestRequest::post().uri(uri)
+            .append_header(("Host", "test.example.com"))
+            .append_header(("Date", date))
+            .append_header(("Digest", digest))
+            .append_header(("Content-Type", "application/activity+json"))
+            .append_header((
+                "Signature",
+                format!(
+                    "keyId=\"{}#main-key\",algorithm=\"rsa-sha256\",headers=\"(request-target) host date digest\",signature=\"{}\"",
+                    REMOTE_ACTOR, signer.sign(signing_string.as_bytes())
+                )
+            ))
+            .set_payload(body.to_string())
+    }
+
+    // sign fetch as the remote actor
+    fn signed_fetch(uri: &str, signer: &User) -> test::TestRequest {
+        let date = Date(SystemTime::now().into()).to_string();
+        let signing_string = format!(
+            "(request-target): get {}\nhost: test.example.com\ndate: {}",
+            uri, date
+        );
+        test::TestRequest::get().uri(uri)
+            .append_header(("Host", "test.example.com"))
+            .append_header(("Date", date))
+            .append_header((
+                "Signature",
+                format!(
+                    "keyId=\"{}#main-key\",algorithm=\"rsa-sha256\",headers=\"(request-target) host date\",signature=\"{}\"",
+                    REMOTE_ACTOR, signer.sign(signing_string.as_bytes())
+                )
+            ))
+    }
+
+    #[actix_web::test]
+    async fn test() {
+        let _ = env_logger::try_init();
+
+        env::set_var("ENV", "test");
+        let app = test::init_service(create_app()).await;
+
+        // setup database
+        let db = Database::connect(dotenv::var("DATABASE_URL").unwrap()).await.unwrap();
+        let _ = Migrator::fresh(&db).await;
+
+        // auth header
+        let api_key = ("x-admin-api-key", dotenv::var("ADMIN_API_KEY").unwrap());
+
+        // add user
+        let res = test::TestRequest::post().uri("/admin/users")
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"username": "inbox", "display_name": "Inbox One"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserResponse = test::read_body_json(res).await;
+        let uid = body.id;
+        let inbox = format!("/users/{}/inbox", uid);
+
+        // remote actor (already resolved)
+        let remote = User::new("remote", "Remote One");
+        remote_actor::ActiveModel {
+            id: Set(REMOTE_ACTOR.to_string()),
+            preferred_username: Set("remote".to_string()),
+            inbox: Set(format!("{}/inbox", REMOTE_ACTOR)),
+            shared_inbox: Set(None),
+            public_key_id: Set(format!("{}#main-key", REMOTE_ACTOR)),
+            public_key_pem: Set(String::from_utf8(remote.key_pair.public_key.public_key_to_pem().unwrap()).unwrap()),
+            fetched_at: Set(Utc::now().to_rfc3339()),
+        }.insert(&db).await.unwrap();
+
+        // follow request sent to remote actor
+        let follow_id = "http://test.example.com/activities/follow1";
+        let following = following::ActiveModel {
+            id: Default::default(),
+            user_id: Set(uid.clone()),
+            actor: Set(REMOTE_ACTOR.to_string()),
+            object: Set(follow_id.to_string()),
+            status: Set(1),
+            created_at: Set(Utc::now().to_rfc3339()),
+            updated_at: Set(Utc::now().to_rfc3339()),
+        }.insert(&db).await.unwrap();
+
+        // signed by another key (fail)
+        let accept = format!(
+            r#"{{"type": "Accept", "id": "{}/accepts/1", "actor": "{}", "object": {{"type": "Follow", "id": "{}", "actor": "http://test.example.com/users/{}", "object": "{}"}}}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, follow_id, uid, REMOTE_ACTOR
+        );
+        let res = signed_request(&inbox, &accept, &User::new("other", "Other One")).send_request(&app).await;
+        assert_eq!(res.status().as_u16(), 401);
+
+        // accept
+        let res = signed_request(&inbox, &accept, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let res = test::TestRequest::get().uri(&format!("/users/{}/following/{}", uid, following.id))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: UserFollowingResponse = test::read_body_json(res).await;
+        assert_eq!(body.status, "accepted");
+
+        // reject (by id only, through shared inbox)
+        let reject = format!(
+            r#"{{"type": "Reject", "id": "{}/rejects/1", "actor": "{}", "object": "{}"}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, follow_id
+        );
+        let res = signed_request("/inbox", &reject, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let res = test::TestRequest::get().uri(&format!("/users/{}/following/{}", uid, following.id))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: UserFollowingResponse = test::read_body_json(res).await;
+        assert_eq!(body.status, "rejected");
+
+        // follow from remote actor
+        let remote_follow_id = format!("{}/follows/1", REMOTE_ACTOR);
+        let follow = format!(
+            r#"{{"type": "Follow", "id": "{}", "actor": "{}", "object": "http://test.example.com/users/{}"}}"#,
+            remote_follow_id, REMOTE_ACTOR, uid
+        );
+        let res = signed_request(&inbox, &follow, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let followers = follower::Entity::find()
+            .filter(follower::Column::UserId.eq(uid.clone()))
+            .all(&db).await.unwrap();
+        assert_eq!(followers.len(), 1);
+        assert_eq!(followers[0].object, remote_follow_id);
+
+        // accept is queued and retried later since the remote inbox is unreachable
+        let jobs = delivery_job::Entity::find()
+            .filter(delivery_job::Column::Inbox.eq(format!("{}/inbox", REMOTE_ACTOR)))
+            .all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert!(jobs[0].body.contains(r#""type":"Accept""#));
+        let container = Container::new().await;
+        container.delivery_service.deliver_due(&container.delivery_service.client()).await.unwrap();
+        let job = delivery_job::Entity::find_by_id(jobs[0].id).one(&db).await.unwrap().unwrap();
+        assert_eq!(job.status, 1);
+        assert_eq!(job.attempts, 1);
+        assert!(job.last_error.is_some());
+        assert!(job.next_attempt_at > jobs[0].next_attempt_at);
+
+        // the failure is recorded for the host
+        let res = test::TestRequest::get().uri("/admin/instances")
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: InstanceListResponse = test::read_body_json(res).await;
+        assert_eq!(body.instances.len(), 1);
+        assert_eq!(body.instances[0].host, "127.0.0.1");
+        assert_eq!(body.instances[0].consecutive_failures, 1);
+        assert!(body.instances[0].unreachable_since.is_some());
+        assert!(!body.instances[0].suspended);
+
+        // retried delivery of the same follow
+        let res = signed_request(&inbox, &follow, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+
+        // the same activity is recorded once
+        let repository = ProcessedActivitySeaORMRepository::new(db.clone());
+        let processed = ProcessedActivity::new(&format!("{}/likes/0", REMOTE_ACTOR), &uid);
+        assert!(repository.add(&processed).await.unwrap());
+        assert!(!repository.add(&processed).await.unwrap());
+
+        // records older than the retention are pruned
+        let expired = processed_activity::ActiveModel {
+            activity_id: Set(format!("{}/likes/old", REMOTE_ACTOR)),
+            user_id: Set(uid.clone()),
+            created_at: Set((Utc::now() - Duration::days(30)).to_rfc3339()),
+            ..Default::default()
+        }.insert(&db).await.unwrap();
+        container.activity_pub_usecase.prune_processed_activities().await.unwrap();
+        assert!(processed_activity::Entity::find_by_id(expired.id).one(&db).await.unwrap().is_none());
+        assert!(!repository.add(&processed).await.unwrap());
+
+        // the host is back since it sends activities
+        let res = test::TestRequest::get().uri("/admin/instances")
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: InstanceListResponse = test::read_body_json(res).await;
+        assert_eq!(body.instances[0].consecutive_failures, 0);
+        assert!(body.instances[0].unreachable_since.is_none());
+        assert_eq!(follower::Entity::find().filter(follower::Column::UserId.eq(uid.clone())).all(&db).await.unwrap().len(), 1);
+
+        // undo of another follow (nothing to do)
+        let undo = format!(
+            r#"{{"type": "Undo", "id": "{}/undos/0", "actor": "{}", "object": {{"type": "Follow", "id": "{}/follows/0", "actor": "{}", "object": "http://test.example.com/users/{}"}}}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, REMOTE_ACTOR, REMOTE_ACTOR, uid
+        );
+        let res = signed_request(&inbox, &undo, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        assert_eq!(follower::Entity::find().filter(follower::Column::UserId.eq(uid.clone())).all(&db).await.unwrap().len(), 1);
+
+        // undo of the follow by another actor (fail)
+        let undo = format!(
+            r#"{{"type": "Undo", "id": "{}/undos/1", "actor": "{}", "object": {{"type": "Follow", "id": "{}", "actor": "http://127.0.0.1:1/users/other", "object": "http://test.example.com/users/{}"}}}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, remote_follow_id, uid
+        );
+        let res = signed_request(&inbox, &undo, &remote).send_request(&app).await;
+        assert_eq!(res.status().as_u16(), 400);
+
+        // note from the remote actor, html is sanitized
+        let create = format!(
+            r#"{{"type": "Create", "id": "{}/notes/1/activity", "actor": "{}", "to": ["http://test.example.com/users/{}"], "object": {{"type": "Note", "id": "{}/notes/1", "attributedTo": "{}", "content": "<p>hello<script>alert(1)</script></p>", "summary": "<b>cw</b><script>alert(2)</script>", "to": ["http://test.example.com/users/{}"]}}}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, uid, REMOTE_ACTOR, REMOTE_ACTOR, uid
+        );
+        let res = signed_request(&inbox, &create, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let notes = remote_note::Entity::find().filter(remote_note::Column::UserId.eq(uid.clone())).all(&db).await.unwrap();
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].object_id, format!("{}/notes/1", REMOTE_ACTOR));
+        assert_eq!(notes[0].content, "<p>hello</p>");
+        assert_eq!(notes[0].summary, Some("<b>cw</b>".to_string()));
+
+        // like and announce (nothing stored)
+        let like = format!(
+            r#"{{"type": "Like", "id": "{}/likes/1", "actor": "{}", "object": "http://test.example.com/notes/1"}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR
+        );
+        let res = signed_request(&inbox, &like, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let announce = format!(
+            r#"{{"type": "Announce", "id": "{}/announces/1", "actor": "{}", "object": "http://test.example.com/notes/1", "to": ["https://www.w3.org/ns/activitystreams#Public"]}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR
+        );
+        let res = signed_request(&inbox, &announce, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+
+        // undo like (nothing stored)
+        let undo = format!(
+            r#"{{"type": "Undo", "id": "{}/undos/2", "actor": "{}", "object": {{"type": "Like", "id": "{}/likes/1", "actor": "{}", "object": "http://test.example.com/notes/1"}}}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, REMOTE_ACTOR, REMOTE_ACTOR
+        );
+        let res = signed_request(&inbox, &undo, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        assert_eq!(follower::Entity::find().filter(follower::Column::UserId.eq(uid.clone())).all(&db).await.unwrap().len(), 1);
+
+        // undo announce (nothing stored)
+        let undo = format!(
+            r#"{{"type": "Undo", "id": "{}/undos/4", "actor": "{}", "object": {{"type": "Announce", "id": "{}/announces/1", "actor": "{}", "object": "http://test.example.com/notes/1"}}}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, REMOTE_ACTOR, REMOTE_ACTOR
+        );
+        let res = signed_request(&inbox, &undo, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+
+        // block and its undo (nothing stored)
+        let block = format!(
+            r#"{{"type": "Block", "id": "{}/blocks/1", "actor": "{}", "object": "http://test.example.com/users/{}"}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, uid
+        );
+        let res = signed_request(&inbox, &block, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let undo = format!(
+            r#"{{"type": "Undo", "id": "{}/undos/5", "actor": "{}", "object": {{"type": "Block", "id": "{}/blocks/1", "actor": "{}", "object": "http://test.example.com/users/{}"}}}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, REMOTE_ACTOR, REMOTE_ACTOR, uid
+        );
+        let res = signed_request(&inbox, &undo, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        assert_eq!(follower::Entity::find().filter(follower::Column::UserId.eq(uid.clone())).all(&db).await.unwrap().len(), 1);
+
+        // undo follow
+        let undo = format!(
+            r#"{{"type": "Undo", "id": "{}/undos/3", "actor": "{}", "object": {{"type": "Follow", "id": "{}", "actor": "{}", "object": "http://test.example.com/users/{}"}}}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, remote_follow_id, REMOTE_ACTOR, uid
+        );
+        let res = signed_request(&inbox, &undo, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        assert!(follower::Entity::find().filter(follower::Column::UserId.eq(uid.clone())).all(&db).await.unwrap().is_empty());
+
+        // undo again (already undone)
+        let res = signed_request(&inbox, &undo, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+
+        // follow again twice with new ids (remote server lost the state)
+        for n in 2..4 {
+            let follow = format!(
+                r#"{{"type": "Follow", "id": "{}/follows/{}", "actor": "{}", "object": "http://test.example.com/users/{}"}}"#,
+                REMOTE_ACTOR, n, REMOTE_ACTOR, uid
+            );
+            let res = signed_request("/inbox", &follow, &remote).send_request(&app).await;
+            assert!(res.status().is_success());
+        }
+        let followers = follower::Entity::find()
+            .filter(follower::Column::UserId.eq(uid.clone()))
+            .all(&db).await.unwrap();
+        assert_eq!(followers.len(), 1);
+        assert_eq!(followers[0].object, format!("{}/follows/3", REMOTE_ACTOR));
+
+        // followers only note
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes", uid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "for followers", "visibility": "followers"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: serde_json::Value = test::read_body_json(res).await;
+        let note = format!("/notes/{}", body["id"].as_str().unwrap());
+
+        // fetched by the follower
+        let res = signed_fetch(&note, &remote).send_request(&app).await;
+        assert_eq!(res.status().as_u16(), 200);
+        let body: serde_json::Value = test::read_body_json(res).await;
+        assert_eq!(body["content"], "for followers");
+
+        // signed by another key (fail)
+        let res = signed_fetch(&note, &User::new("other", "Other One")).send_request(&app).await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // unsigned (fail)
+        let res = test::TestRequest::get().uri(&note).send_request(&app).await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // locked user
+        let res = test::TestRequest::post().uri("/admin/users")
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"username": "locked", "display_name": "Locked One", "locked": true}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserResponse = test::read_body_json(res).await;
+        assert!(body.locked);
+        let lid = body.id;
+        let locked_inbox = format!("/users/{}/inbox", lid);
+        let res = test::TestRequest::get().uri("/@locked").send_request(&app).await;
+        let actor: serde_json::Value = test::read_body_json(res).await;
+        assert_eq!(actor["manuallyApprovesFollowers"], true);
+
+        // follow to another user (fail)
+        let follow = format!(
+            r#"{{"type": "Follow", "id": "{}/follows/9", "actor": "{}", "object": "http://test.example.com/users/{}"}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, uid
+        );
+        let res = signed_request(&locked_inbox, &follow, &remote).send_request(&app).await;
+        assert_eq!(res.status().as_u16(), 400);
+
+        // follow to the locked user is pending, other users followed by the actor are untouched
+        let jobs = delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(uid.clone())).all(&db).await.unwrap().len();
+        let follow = format!(
+            r#"{{"type": "Follow", "id": "{}/follows/10", "actor": "{}", "object": "http://test.example.com/users/{}"}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, lid
+        );
+        let res = signed_request("/inbox", &follow, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let followers = follower::Entity::find().filter(follower::Column::UserId.eq(uid.clone())).all(&db).await.unwrap();
+        assert_eq!(followers[0].object, format!("{}/follows/3", REMOTE_ACTOR));
+        assert_eq!(delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(uid.clone())).all(&db).await.unwrap().len(), jobs);
+        assert!(follower::Entity::find().filter(follower::Column::UserId.eq(lid.clone())).all(&db).await.unwrap().is_empty());
+        assert!(delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(lid.clone())).all(&db).await.unwrap().is_empty());
+        let res = test::TestRequest::get().uri(&format!("/users/{}/follow_requests", lid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserFollowRequestListResponse = test::read_body_json(res).await;
+        assert_eq!(body.follow_requests.len(), 1);
+        assert_eq!(body.follow_requests[0].actor, REMOTE_ACTOR);
+
+        // withdrawn by undo
+        let undo = format!(
+            r#"{{"type": "Undo", "id": "{}/undos/10", "actor": "{}", "object": {{"type": "Follow", "id": "{}/follows/10", "actor": "{}", "object": "http://test.example.com/users/{}"}}}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, REMOTE_ACTOR, REMOTE_ACTOR, lid
+        );
+        let res = signed_request(&locked_inbox, &undo, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let res = test::TestRequest::get().uri(&format!("/users/{}/follow_requests", lid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: UserFollowRequestListResponse = test::read_body_json(res).await;
+        assert!(body.follow_requests.is_empty());
+
+        // reject
+        for n in 11..13 {
+            let follow = format!(
+                r#"{{"type": "Follow", "id": "{}/follows/{}", "actor": "{}", "object": "http://test.example.com/users/{}"}}"#,
+                REMOTE_ACTOR, n, REMOTE_ACTOR, lid
+            );
+            let res = signed_request(&locked_inbox, &follow, &remote).send_request(&app).await;
+            assert!(res.status().is_success());
+        }
+        let res = test::TestRequest::get().uri(&format!("/users/{}/follow_requests", lid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: UserFollowRequestListResponse = test::read_body_json(res).await;
+        assert_eq!(body.follow_requests.len(), 1);
+        let res = test::TestRequest::post().uri(&format!("/users/{}/follow_requests/{}/reject", lid, body.follow_requests[0].id))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        assert!(follower::Entity::find().filter(follower::Column::UserId.eq(lid.clone())).all(&db).await.unwrap().is_empty());
+        let jobs = delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(lid.clone())).all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        let reject: serde_json::Value = serde_json::from_str(&jobs[0].body).unwrap();
+        assert_eq!(reject["type"], "Reject");
+        assert_eq!(reject["actor"], format!("http://test.example.com/users/{}", lid));
+        assert_eq!(reject["object"]["id"], format!("{}/follows/12", REMOTE_ACTOR));
+        assert_eq!(reject["object"]["actor"], REMOTE_ACTOR);
+
+        // accept
+        let follow = format!(
+            r#"{{"type": "Follow", "id": "{}/follows/13", "actor": "{}", "object": "http://test.example.com/users/{}"}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, lid
+        );
+        let res = signed_request(&locked_inbox, &follow, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let res = test::TestRequest::get().uri(&format!("/users/{}/follow_requests", lid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: UserFollowRequestListResponse = test::read_body_json(res).await;
+        let rid = body.follow_requests[0].id;
+        let res = test::TestRequest::post().uri(&format!("/users/{}/follow_requests/{}/accept", lid, rid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let followers = follower::Entity::find().filter(follower::Column::UserId.eq(lid.clone())).all(&db).await.unwrap();
+        assert_eq!(followers.len(), 1);
+        assert_eq!(followers[0].object, format!("{}/follows/13", REMOTE_ACTOR));
+        let jobs = delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(lid.clone())).all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 2);
+        let accept: serde_json::Value = serde_json::from_str(&jobs[1].body).unwrap();
+        assert_eq!(accept["type"], "Accept");
+        assert_eq!(accept["object"]["id"], format!("{}/follows/13", REMOTE_ACTOR));
+
+        // accept again (fail)
+        let res = test::TestRequest::post().uri(&format!("/users/{}/follow_requests/{}/accept", lid, rid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // list without admin api-key (fail)
+        let res = test::TestRequest::get().uri(&format!("/users/{}/follow_requests", lid))
+            .send_request(&app)
+            .await;
+        assert!(!res.status().is_success());
+
+        // remove follower
+        let remove = format!(r#"{{"actor": "{}"}}"#, REMOTE_ACTOR);
+        let res = test::TestRequest::post().uri(&format!("/users/{}/followers/remove", lid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(remove.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        assert!(follower::Entity::find().filter(follower::Column::UserId.eq(lid.clone())).all(&db).await.unwrap().is_empty());
+        let jobs = delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(lid.clone())).all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 3);
+        let reject: serde_json::Value = serde_json::from_str(&jobs[2].body).unwrap();
+        assert_eq!(reject["type"], "Reject");
+        assert_eq!(reject["object"]["id"], format!("{}/follows/13", REMOTE_ACTOR));
+
+        // remove again (fail)
+        let res = test::TestRequest::post().uri(&format!("/users/{}/followers/remove", lid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(remove.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // block drops the pending request
+        let follow = format!(
+            r#"{{"type": "Follow", "id": "{}/follows/14", "actor": "{}", "object": "http://test.example.com/users/{}"}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, lid
+        );
+        let res = signed_request(&locked_inbox, &follow, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let block = format!(r#"{{"actor": "{}"}}"#, REMOTE_ACTOR);
+        let res = test::TestRequest::post().uri(&format!("/users/{}/blocks", lid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(block.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserBlockResponse = test::read_body_json(res).await;
+        assert_eq!(body.actor, REMOTE_ACTOR);
+        let bid = body.id;
+        let res = test::TestRequest::get().uri(&format!("/users/{}/follow_requests", lid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: UserFollowRequestListResponse = test::read_body_json(res).await;
+        assert!(body.follow_requests.is_empty());
+        let jobs = delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(lid.clone())).all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 4);
+        let sent: serde_json::Value = serde_json::from_str(&jobs[3].body).unwrap();
+        assert_eq!(sent["type"], "Block");
+        assert_eq!(sent["actor"], format!("http://test.example.com/users/{}", lid));
+        assert_eq!(sent["object"], REMOTE_ACTOR);
+        let block_id = sent["id"].as_str().unwrap().to_string();
+
+        // block again (fail)
+        let res = test::TestRequest::post().uri(&format!("/users/{}/blocks", lid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(block.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 400);
+
+        // follow from blocked actor is dropped
+        let follow = format!(
+            r#"{{"type": "Follow", "id": "{}/follows/15", "actor": "{}", "object": "http://test.example.com/users/{}"}}"#,
+            REMOTE_ACTOR, REMOTE_ACTOR, lid
+        );
+        let res = signed_request(&locked_inbox, &follow, &remote).send_request(&app).await;
+        assert!(res.status().is_success());
+        let res = test::TestRequest::get().uri(&format!("/users/{}/follow_requests", lid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: UserFollowRequestListResponse = test::read_body_json(res).await;
+        assert!(body.follow_requests.is_empty());
+
+        // list blocks
+        let res = test::TestRequest::get().uri(&format!("/users/{}/blocks", lid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserBlockListResponse = test::read_body_json(res).await;
+        assert_eq!(body.blocks.len(), 1);
+        assert_eq!(body.blocks[0].id, bid);
+
+        // unblock
+        let res = test::TestRequest::delete().uri(&format!("/users/{}/blocks/{}", lid, bid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let jobs = delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(lid.clone())).all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 5);
+        let undo: serde_json::Value = serde_json::from_str(&jobs[4].body).unwrap();
+        assert_eq!(undo["type"], "Undo");
+        assert_eq!(undo["object"]["type"], "Block");
+        assert_eq!(undo["object"]["id"], block_id);
+        let res = test::TestRequest::get().uri(&format!("/users/{}/blocks", lid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: UserBlockListResponse = test::read_body_json(res).await;
+        assert!(body.blocks.is_empty());
+
+        // unblock again (fail)
+        let res = test::TestRequest::delete().uri(&format!("/users/{}/blocks/{}", lid, bid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // block the actor which can not be resolved, nothing is sent
+        let res = test::TestRequest::post().uri(&format!("/users/{}/blocks", lid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"actor": "http://127.0.0.1:1/users/gone"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserBlockResponse = test::read_body_json(res).await;
+        assert_eq!(body.actor, "http://127.0.0.1:1/users/gone");
+        let gone = body.id;
+        let jobs = delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(lid.clone())).all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 5);
+
+        // unblock the actor which can not be resolved
+        let res = test::TestRequest::delete().uri(&format!("/users/{}/blocks/{}", lid, gone))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let res = test::TestRequest::get().uri(&format!("/users/{}/blocks", lid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: UserBlockListResponse = test::read_body_json(res).await;
+        assert!(body.blocks.is_empty());
+        let jobs = delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(lid.clone())).all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 5);
+
+        // block invalid actor (fail)
+        let res = test::TestRequest::post().uri(&format!("/users/{}/blocks", lid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"actor": "foo"}"#)
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+    }
+}
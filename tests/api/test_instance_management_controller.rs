@@ -0,0 +1,144 @@
+#[cfg(test)]
+mod test_instance_management_controller {
+    use std::env;
+    use std::sync::Arc;
+    use actix_web::{App, HttpResponse, HttpServer, test, web};
+    use chrono::{DateTime, Duration, Utc};
+    use sea_orm::{ActiveModelTrait, Database, EntityTrait};
+    use sea_orm::ActiveValue::Set;
+    use gekidan::app::container::Container;
+    use gekidan::app::factory::create_app;
+    use gekidan::domain::instance::instance_service::InstanceService;
+    use gekidan::infrastructure::repositories::delivery_job::DeliveryJobSeaORMRepository;
+    use gekidan::infrastructure::repositories::instance_health::InstanceHealthSeaORMRepository;
+    use gekidan::infrastructure::databases::entities::{delivery_job, instance_health};
+    use gekidan::presentation::controllers::instance_management::InstanceListResponse;
+    use gekidan::presentation::controllers::user_management::UserResponse;
+    use migrations::{Migrator, MigratorTrait};
+
+    #[actix_web::test]
+    async fn test() {
+        let _ = env_logger::try_init();
+
+        env::set_var("ENV", "test");
+        let app = test::init_service(create_app()).await;
+
+        // setup database
+        let db = Database::connect(dotenv::var("DATABASE_URL").unwrap()).await.unwrap();
+        let _ = Migrator::fresh(&db).await;
+
+        // auth header
+        let api_key = ("x-admin-api-key", dotenv::var("ADMIN_API_KEY").unwrap());
+
+        // list without key (fail)
+        let res = test::TestRequest::get().uri("/admin/instances")
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 401);
+
+        // list
+        let res = test::TestRequest::get().uri("/admin/instances")
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: InstanceListResponse = test::read_body_json(res).await;
+        assert_eq!(body.instances.len(), 0);
+
+        // host unreachable for 8 days, last tried just now
+        let now = Utc::now();
+        instance_health::ActiveModel {
+            host: Set("127.0.0.1".to_string()),
+            consecutive_failures: Set(20),
+            last_success_at: Set(None),
+            last_failure_at: Set(Some(now.to_rfc3339())),
+            unreachable_since: Set(Some((now - Duration::days(8)).to_rfc3339())),
+            updated_at: Set(now.to_rfc3339()),
+        }.insert(&db).await.unwrap();
+
+        let res = test::TestRequest::get().uri("/admin/instances")
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: InstanceListResponse = test::read_body_json(res).await;
+        assert_eq!(body.instances.len(), 1);
+        assert_eq!(body.instances[0].host, "127.0.0.1");
+        assert_eq!(body.instances[0].consecutive_failures, 20);
+        assert!(body.instances[0].suspended);
+
+        // mock inbox on the suspended host
+        let server = HttpServer::new(|| App::new().default_service(web::to(HttpResponse::Accepted)))
+            .bind(("127.0.0.1", 0))
+            .unwrap();
+        let inbox = format!("http://127.0.0.1:{}/inbox", server.addrs()[0].port());
+        actix_web::rt::spawn(server.run());
+
+        // delivery to the suspended host is held back until the next probe
+        let res = test::TestRequest::post().uri("/admin/users")
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"username": "hoge", "display_name": "Hoge One"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let user: UserResponse = test::read_body_json(res).await;
+        let job = delivery_job::ActiveModel {
+            user_id: Set(user.id),
+            inbox: Set(inbox),
+            body: Set("{}".to_string()),
+            status: Set(1),
+            attempts: Set(0),
+            next_attempt_at: Set(now.to_rfc3339()),
+            created_at: Set(now.to_rfc3339()),
+            updated_at: Set(now.to_rfc3339()),
+            ..Default::default()
+        }.insert(&db).await.unwrap();
+        let container = Container::new().await;
+        container.delivery_service.deliver_due(&container.delivery_service.client()).await.unwrap();
+        let held = delivery_job::Entity::find_by_id(job.id).one(&db).await.unwrap().unwrap();
+        assert_eq!(held.status, 1);
+        assert_eq!(held.attempts, 0);
+        assert_eq!(held.last_error, Some("Host is unreachable".to_string()));
+        let next_attempt_at = DateTime::parse_from_rfc3339(&held.next_attempt_at).unwrap();
+        assert_eq!(next_attempt_at.timestamp(), (now + Duration::hours(1)).timestamp());
+        let health = instance_health::Entity::find_by_id("127.0.0.1".to_string()).one(&db).await.unwrap().unwrap();
+        assert_eq!(health.consecutive_failures, 20);
+
+        // the host is back, held deliveries are sent right away
+        let instance_service = InstanceService::new(
+            604800,
+            Arc::new(InstanceHealthSeaORMRepository::new(db.clone())),
+            Arc::new(DeliveryJobSeaORMRepository::new(db.clone())),
+        );
+        instance_service.record_success("http://127.0.0.1/").await.unwrap();
+        container.delivery_service.deliver_due(&container.delivery_service.client()).await.unwrap();
+        let delivered = delivery_job::Entity::find_by_id(job.id).one(&db).await.unwrap().unwrap();
+        assert_eq!(delivered.status, 2);
+        assert_eq!(delivered.attempts, 1);
+        assert_eq!(delivered.last_status, Some(202));
+
+        // finished jobs are swept after the retention, pending ones are kept
+        let expired = now - Duration::days(30);
+        let mut old_jobs = vec![];
+        for status in [1, 2, 3] {
+            let old = delivery_job::ActiveModel {
+                user_id: Set(delivered.user_id.clone()),
+                inbox: Set("http://127.0.0.1:1/inbox".to_string()),
+                body: Set("{}".to_string()),
+                status: Set(status),
+                attempts: Set(1),
+                next_attempt_at: Set(now.to_rfc3339()),
+                created_at: Set(expired.to_rfc3339()),
+                updated_at: Set(expired.to_rfc3339()),
+                ..Default::default()
+            }.insert(&db).await.unwrap();
+            old_jobs.push(old.id);
+        }
+        container.delivery_service.sweep_finished().await.unwrap();
+        assert!(delivery_job::Entity::find_by_id(old_jobs[0]).one(&db).await.unwrap().is_some());
+        assert!(delivery_job::Entity::find_by_id(old_jobs[1]).one(&db).await.unwrap().is_none());
+        assert!(delivery_job::Entity::find_by_id(old_jobs[2]).one(&db).await.unwrap().is_none());
+        assert!(delivery_job::Entity::find_by_id(job.id).one(&db).await.unwrap().is_some());
+    }
+}
@@ -0,0 +1,153 @@
+#[cfg(test)]
+mod test_user_following_controller {
+    use std::env;
+    use actix_web::test;
+    use chrono::Utc;
+    use gekidan::app::factory::create_app;
+    use gekidan::infrastructure::databases::entities::following;
+    use gekidan::presentation::controllers::user_following::UserFollowingListResponse;
+    use gekidan::presentation::controllers::user_management::UserResponse;
+    use migrations::{Migrator, MigratorTrait};
+    use sea_orm::{ActiveModelTrait, Database, EntityTrait};
+    use sea_orm::ActiveValue::Set;
+
+    #[actix_web::test]
+    async fn test() {
+        let _ = env_logger::try_init();
+
+        env::set_var("ENV", "test");
+        let app = test::init_service(create_app()).await;
+
+        // setup database
+        let db = Database::connect(dotenv::var("DATABASE_URL").unwrap()).await.unwrap();
+        let _ = Migrator::fresh(&db).await;
+
+        // auth header
+        let api_key = ("x-admin-api-key", dotenv::var("ADMIN_API_KEY").unwrap());
+
+        // add user
+        let res = test::TestRequest::post().uri("/admin/users")
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"username": "follower", "display_name": "Follower One"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserResponse = test::read_body_json(res).await;
+        let uid = body.id;
+
+        // follow invalid account (fail)
+        let res = test::TestRequest::post().uri(&format!("/users/{}/following", uid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"account": "foo"}"#)
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // follow unreachable account (fail)
+        let res = test::TestRequest::post().uri(&format!("/users/{}/following", uid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"account": "foo@127.0.0.1:1"}"#)
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // get not existing following (fail)
+        let res = test::TestRequest::get().uri(&format!("/users/{}/following/1", uid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // unfollow not existing following (fail)
+        let res = test::TestRequest::delete().uri(&format!("/users/{}/following/1", uid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // list followings
+        let now = Utc::now();
+        let rejected = following::ActiveModel {
+            user_id: Set(uid.clone()),
+            actor: Set("http://127.0.0.1:1/users/rejected".to_string()),
+            object: Set("http://test.example.com/activities/rejected".to_string()),
+            status: Set(3),
+            created_at: Set(now.to_rfc3339()),
+            updated_at: Set(now.to_rfc3339()),
+            ..Default::default()
+        }.insert(&db).await.unwrap();
+        let res = test::TestRequest::get().uri(&format!("/users/{}/following/list", uid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserFollowingListResponse = test::read_body_json(res).await;
+        assert_eq!(body.followings.len(), 1);
+        assert_eq!(body.followings[0].id, rejected.id);
+        assert_eq!(body.followings[0].status, "rejected");
+
+        // list followings filtered by status
+        let res = test::TestRequest::get().uri(&format!("/users/{}/following/list?status=rejected", uid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserFollowingListResponse = test::read_body_json(res).await;
+        assert_eq!(body.followings.len(), 1);
+        let res = test::TestRequest::get().uri(&format!("/users/{}/following/list?status=pending", uid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserFollowingListResponse = test::read_body_json(res).await;
+        assert!(body.followings.is_empty());
+
+        // list followings with unknown status (fail)
+        let res = test::TestRequest::get().uri(&format!("/users/{}/following/list?status=foo", uid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 400);
+
+        // list followings of unknown user (fail)
+        let res = test::TestRequest::get().uri("/users/unknown/following/list")
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // list followings without admin api-key (fail)
+        let res = test::TestRequest::get().uri(&format!("/users/{}/following/list", uid))
+            .send_request(&app)
+            .await;
+        assert!(!res.status().is_success());
+
+        // unfollow the actor which can not be resolved anymore
+        let gone = following::ActiveModel {
+            user_id: Set(uid.clone()),
+            actor: Set("http://127.0.0.1:1/users/gone".to_string()),
+            object: Set("http://test.example.com/activities/gone".to_string()),
+            status: Set(2),
+            created_at: Set(now.to_rfc3339()),
+            updated_at: Set(now.to_rfc3339()),
+            ..Default::default()
+        }.insert(&db).await.unwrap();
+        let res = test::TestRequest::delete().uri(&format!("/users/{}/following/{}", uid, gone.id))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        assert!(following::Entity::find_by_id(gone.id).one(&db).await.unwrap().is_none());
+
+        // follow without admin api-key (fail)
+        let res = test::TestRequest::post().uri(&format!("/users/{}/following", uid))
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"account": "foo@127.0.0.1:1"}"#)
+            .send_request(&app)
+            .await;
+        assert!(!res.status().is_success());
+    }
+}
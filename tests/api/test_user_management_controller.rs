@@ -1,11 +1,25 @@
 #[cfg(test)]
 mod test_user_management_controller {
     use std::env;
-    use actix_web::test;
+    use std::sync::{Arc, Mutex};
+    use actix_web::{App, HttpRequest, HttpResponse, HttpServer, test, web};
+    use chrono::Utc;
     use gekidan::app::factory::create_app;
+    use gekidan::infrastructure::databases::entities::{delivery_job, follower, note};
     use gekidan::presentation::controllers::user_management::{UserListResponse, UserResponse};
     use migrations::{Migrator, MigratorTrait};
-    use sea_orm::Database;
+    use sea_orm::{ActiveModelTrait, ColumnTrait, Database, EntityTrait, QueryFilter};
+    use sea_orm::ActiveValue::Set;
+    use serde_json::Value;
+
+    // (signature, body) of received activities
+    type Received = Arc<Mutex<Vec<(String, String)>>>;
+
+    async fn inbox(req: HttpRequest, body: String, received: web::Data<Received>) -> HttpResponse {
+        let signature = req.headers().get("signature").map(|v| v.to_str().unwrap().to_string()).unwrap_or_default();
+        received.lock().unwrap().push((signature, body));
+        HttpResponse::Accepted().finish()
+    }
 
     #[actix_web::test]
     async fn test() {
@@ -77,6 +91,16 @@ mod test_user_management_controller {
             .await;
         assert!(!res.status().is_success());
 
+        // remote follower
+        follower::ActiveModel {
+            user_id: Set(uid2.clone()),
+            actor: Set("https://remote.example.com/users/foo".to_string()),
+            object: Set("https://remote.example.com/follows/1".to_string()),
+            inbox: Set("https://remote.example.com/users/foo/inbox".to_string()),
+            created_at: Set(Utc::now().to_rfc3339()),
+            ..Default::default()
+        }.insert(&db).await.unwrap();
+
         // update
         let res = test::TestRequest::put().uri(&format!("/admin/users/{}", &uid2))
             .append_header(api_key.clone())
@@ -88,6 +112,51 @@ mod test_user_management_controller {
         let body: UserResponse = test::read_body_json(res).await;
         assert_eq!(body.username, "hoge_two");
 
+        // updated profile is delivered to the follower
+        let jobs = delivery_job::Entity::find()
+            .filter(delivery_job::Column::UserId.eq(uid2.clone()))
+            .all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].inbox, "https://remote.example.com/users/foo/inbox");
+        let update: Value = serde_json::from_str(&jobs[0].body).unwrap();
+        assert_eq!(update["type"], "Update");
+        assert_eq!(update["actor"], format!("http://test.example.com/users/{}", uid2));
+        assert_eq!(update["object"]["type"], "Person");
+        assert_eq!(update["object"]["preferredUsername"], "hoge_two");
+        assert_eq!(update["object"]["name"], "Hoge Two");
+
+        // rotate key
+        let res = test::TestRequest::post().uri(&format!("/admin/users/{}/rotate_key", &uid2))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+
+        // new key is delivered to the follower
+        let jobs = delivery_job::Entity::find()
+            .filter(delivery_job::Column::UserId.eq(uid2.clone()))
+            .all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 2);
+        let rotated: Value = serde_json::from_str(&jobs[1].body).unwrap();
+        assert_eq!(rotated["type"], "Update");
+        assert_ne!(rotated["id"], update["id"]);
+        assert_ne!(rotated["object"]["publicKey"]["publicKeyPem"], update["object"]["publicKey"]["publicKeyPem"]);
+
+        // actor document serves the new key
+        let res = test::TestRequest::get().uri("/@hoge_two")
+            .append_header(("Accept", "application/activity+json"))
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let actor: Value = test::read_body_json(res).await;
+        assert_eq!(actor["publicKey"]["publicKeyPem"], rotated["object"]["publicKey"]["publicKeyPem"]);
+
+        // rotate key without admin api-key (fail)
+        let res = test::TestRequest::post().uri(&format!("/admin/users/{}/rotate_key", &uid2))
+            .send_request(&app)
+            .await;
+        assert!(!res.status().is_success());
+
         // get
         let res = test::TestRequest::get().uri(&format!("/admin/users/{}", &uid2))
             .append_header(api_key.clone())
@@ -97,6 +166,31 @@ mod test_user_management_controller {
         let body: UserResponse = test::read_body_json(res).await;
         assert_eq!(body.username, "hoge_two");
 
+        // remote follower with a mock inbox, and a note
+        let received: Received = Arc::new(Mutex::new(vec![]));
+        let data = web::Data::new(received.clone());
+        let server = HttpServer::new(move || App::new().app_data(data.clone()).route("/inbox", web::post().to(inbox)))
+            .workers(1)
+            .bind(("127.0.0.1", 0))
+            .unwrap();
+        let remote_inbox = format!("http://127.0.0.1:{}/inbox", server.addrs()[0].port());
+        actix_web::rt::spawn(server.run());
+        follower::ActiveModel {
+            user_id: Set(uid1.clone()),
+            actor: Set("http://127.0.0.1/users/foo".to_string()),
+            object: Set("http://127.0.0.1/follows/1".to_string()),
+            inbox: Set(remote_inbox),
+            created_at: Set(Utc::now().to_rfc3339()),
+            ..Default::default()
+        }.insert(&db).await.unwrap();
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes", uid1))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "foobarbaz111"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+
         // delete
         let res = test::TestRequest::delete().uri(&format!("/admin/users/{}", &uid1))
             .append_header(api_key.clone())
@@ -104,6 +198,56 @@ mod test_user_management_controller {
             .await;
         assert!(res.status().is_success());
 
+        // delete is delivered at once, signed with the deleted key
+        {
+            let received = received.lock().unwrap();
+            assert_eq!(received.len(), 1);
+            let (signature, body) = &received[0];
+            assert!(signature.contains(&format!("keyId=\"http://test.example.com/users/{}#main-key\"", uid1)));
+            let delete: Value = serde_json::from_str(body).unwrap();
+            assert_eq!(delete["type"], "Delete");
+            assert_eq!(delete["object"], format!("http://test.example.com/users/{}", uid1));
+        }
+
+        // data of the user is deleted
+        assert!(follower::Entity::find().filter(follower::Column::UserId.eq(uid1.clone())).all(&db).await.unwrap().is_empty());
+        assert!(note::Entity::find().filter(note::Column::UserId.eq(uid1.clone())).all(&db).await.unwrap().is_empty());
+        assert!(delivery_job::Entity::find().filter(delivery_job::Column::UserId.eq(uid1.clone())).all(&db).await.unwrap().is_empty());
+
+        // actor and webfinger are gone
+        let res = test::TestRequest::get().uri(&format!("/users/{}", uid1))
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 410);
+        let res = test::TestRequest::get().uri("/@hoge")
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 410);
+        let res = test::TestRequest::get().uri("/.well-known/webfinger?resource=acct:hoge@test.example.com")
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 410);
+        let res = test::TestRequest::get().uri("/.well-known/webfinger?resource=acct:nobody@test.example.com")
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // username of the deleted user is not reused (fail)
+        let res = test::TestRequest::post().uri("/admin/users")
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"username": "hoge", "display_name": "Hoge One"}"#)
+            .send_request(&app)
+            .await;
+        assert!(!res.status().is_success());
+
+        // delete again (fail)
+        let res = test::TestRequest::delete().uri(&format!("/admin/users/{}", &uid1))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(!res.status().is_success());
+
         // list
         let res = test::TestRequest::get().uri("/admin/users")
             .append_header(api_key.clone())
@@ -118,5 +262,82 @@ mod test_user_management_controller {
             .send_request(&app)
             .await;
         assert!(!res.status().is_success());
+
+        // add with profile
+        let res = test::TestRequest::post().uri("/admin/users")
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{
+                "username": "hoge3",
+                "display_name": "Hoge Three",
+                "summary": "<p>hello</p>",
+                "avatar_url": "https://media.example.com/avatar.png",
+                "header_url": "https://media.example.com/header.png",
+                "fields": [{"name": "Blog", "value": "https://blog.example.com/"}]
+            }"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserResponse = test::read_body_json(res).await;
+        assert_eq!(body.summary, "<p>hello</p>");
+        assert_eq!(body.avatar_url, Some("https://media.example.com/avatar.png".to_string()));
+        assert_eq!(body.fields.len(), 1);
+        let uid3 = body.id;
+
+        // actor document carries the profile
+        let res = test::TestRequest::get().uri("/@hoge3").send_request(&app).await;
+        assert!(res.status().is_success());
+        let actor: Value = test::read_body_json(res).await;
+        assert_eq!(actor["name"], "Hoge Three");
+        assert_eq!(actor["summary"], "<p>hello</p>");
+        assert_eq!(actor["url"], "http://test.example.com/@hoge3");
+        assert!(actor["published"].is_string());
+        assert_eq!(actor["icon"]["type"], "Image");
+        assert_eq!(actor["icon"]["url"], "https://media.example.com/avatar.png");
+        assert_eq!(actor["image"]["url"], "https://media.example.com/header.png");
+        assert_eq!(actor["attachment"][0]["type"], "PropertyValue");
+        assert_eq!(actor["attachment"][0]["name"], "Blog");
+        assert_eq!(actor["attachment"][0]["value"], "https://blog.example.com/");
+        assert_eq!(actor["endpoints"]["sharedInbox"], "http://test.example.com/inbox");
+
+        // update profile, an empty url removes the avatar
+        let res = test::TestRequest::put().uri(&format!("/admin/users/{}", &uid3))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{
+                "username": "hoge3",
+                "display_name": "Hoge Three",
+                "avatar_url": "",
+                "fields": [{"name": "a", "value": "1"}, {"name": "b", "value": "2"}]
+            }"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserResponse = test::read_body_json(res).await;
+        assert_eq!(body.summary, "<p>hello</p>");
+        assert_eq!(body.avatar_url, None);
+        assert_eq!(body.header_url, Some("https://media.example.com/header.png".to_string()));
+        assert_eq!(body.fields.len(), 2);
+        let res = test::TestRequest::get().uri("/@hoge3").send_request(&app).await;
+        let actor: Value = test::read_body_json(res).await;
+        assert!(actor["icon"].is_null());
+        assert_eq!(actor["attachment"][1]["name"], "b");
+
+        // too many profile fields (fail)
+        let fields = r#"[{"name": "a", "value": "1"}, {"name": "b", "value": "2"}, {"name": "c", "value": "3"}, {"name": "d", "value": "4"}, {"name": "e", "value": "5"}]"#;
+        let res = test::TestRequest::put().uri(&format!("/admin/users/{}", &uid3))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(format!(r#"{{"username": "hoge3", "display_name": "Hoge Three", "fields": {}}}"#, fields))
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 400);
+        let res = test::TestRequest::post().uri("/admin/users")
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(format!(r#"{{"username": "hoge4", "display_name": "Hoge Four", "fields": {}}}"#, fields))
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 400);
     }
 }
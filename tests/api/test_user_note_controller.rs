@@ -2,11 +2,16 @@
 mod test_user_note_controller {
     use std::env;
     use actix_web::test;
+    use chrono::Utc;
     use gekidan::app::factory::create_app;
+    use gekidan::infrastructure::databases::entities::{delivery_job, follower, note_recipient};
     use gekidan::presentation::controllers::user_management::UserResponse;
-    use gekidan::presentation::controllers::user_note::{UserNoteListResponse, UserNoteResponse};
+    use gekidan::presentation::controllers::user_note::{UserNoteHistoryListResponse, UserNoteListResponse, UserNoteResponse};
+    use gekidan::presentation::controllers::user_received_note::ReceivedNoteListResponse;
     use migrations::{Migrator, MigratorTrait};
-    use sea_orm::Database;
+    use sea_orm::{ActiveModelTrait, ColumnTrait, Database, EntityTrait, QueryFilter};
+    use sea_orm::ActiveValue::Set;
+    use serde_json::Value;
 
     #[actix_web::test]
     async fn test() {
@@ -33,6 +38,16 @@ mod test_user_note_controller {
         let body: UserResponse = test::read_body_json(res).await;
         let uid = body.id;
 
+        // remote follower
+        let foo = follower::ActiveModel {
+            user_id: Set(uid.clone()),
+            actor: Set("https://remote.example.com/users/foo".to_string()),
+            object: Set("https://remote.example.com/follows/1".to_string()),
+            inbox: Set("https://remote.example.com/users/foo/inbox".to_string()),
+            created_at: Set(Utc::now().to_rfc3339()),
+            ..Default::default()
+        }.insert(&db).await.unwrap();
+
         // list
         let res = test::TestRequest::get().uri(&format!("/users/{}/notes", uid))
             .append_header(api_key.clone())
@@ -85,6 +100,95 @@ mod test_user_note_controller {
         let body: UserNoteResponse = test::read_body_json(res).await;
         assert_eq!(body.content, "foobarbaz111");
 
+        // edit
+        let res = test::TestRequest::put().uri(&format!("/users/{}/notes/{}", uid, nid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "foobarbaz333"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserNoteResponse = test::read_body_json(res).await;
+        assert_eq!(body.content, "foobarbaz333");
+        assert_ne!(body.updated_at, body.created_at);
+
+        // edit without admin api-key (fail)
+        let res = test::TestRequest::put().uri(&format!("/users/{}/notes/{}", uid, nid))
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "foobarbaz444"}"#)
+            .send_request(&app)
+            .await;
+        assert!(!res.status().is_success());
+
+        // get
+        let res = test::TestRequest::get().uri(&format!("/users/{}/notes/{}", uid, nid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        let body: UserNoteResponse = test::read_body_json(res).await;
+        assert_eq!(body.content, "foobarbaz333");
+
+        // history
+        let res = test::TestRequest::get().uri(&format!("/users/{}/notes/{}/history", uid, nid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserNoteHistoryListResponse = test::read_body_json(res).await;
+        assert_eq!(body.history.len(), 1);
+        assert_eq!(body.history[0].content, "foobarbaz111");
+
+        // update is delivered to the follower
+        let jobs = delivery_job::Entity::find()
+            .filter(delivery_job::Column::UserId.eq(uid.clone()))
+            .all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 3);
+        let update: Value = serde_json::from_str(&jobs[2].body).unwrap();
+        assert_eq!(update["type"], "Update");
+        assert_eq!(update["object"]["type"], "Note");
+        assert_eq!(update["object"]["id"], format!("http://test.example.com/notes/{}", nid));
+        assert_eq!(update["object"]["content"], "foobarbaz333");
+        assert!(update["object"]["updated"].is_string());
+
+        // create activity has its own url
+        let create: Value = serde_json::from_str(&jobs[0].body).unwrap();
+        assert_eq!(create["type"], "Create");
+        assert_eq!(create["id"], format!("http://test.example.com/notes/{}/activity", nid));
+
+        // public url of the note
+        let res = test::TestRequest::get().uri(&format!("/notes/{}", nid))
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 200);
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["type"], "Note");
+        assert_eq!(body["id"], format!("http://test.example.com/notes/{}", nid));
+        assert_eq!(body["url"], format!("http://test.example.com/notes/{}", nid));
+        assert_eq!(body["attributedTo"], format!("http://test.example.com/users/{}", uid));
+        assert_eq!(body["content"], "foobarbaz333");
+        assert_eq!(body["to"][0], "https://www.w3.org/ns/activitystreams#Public");
+
+        // public url of the create activity
+        let res = test::TestRequest::get().uri(&format!("/notes/{}/activity", nid))
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 200);
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["type"], "Create");
+        assert_eq!(body["id"], format!("http://test.example.com/notes/{}/activity", nid));
+        assert_eq!(body["object"]["id"], format!("http://test.example.com/notes/{}", nid));
+
+        // the follower which received the note leaves, another one comes
+        follower::Entity::delete_by_id(foo.id).exec(&db).await.unwrap();
+        follower::ActiveModel {
+            user_id: Set(uid.clone()),
+            actor: Set("https://remote.example.com/users/bar".to_string()),
+            object: Set("https://remote.example.com/follows/2".to_string()),
+            inbox: Set("https://remote.example.com/users/bar/inbox".to_string()),
+            created_at: Set(Utc::now().to_rfc3339()),
+            ..Default::default()
+        }.insert(&db).await.unwrap();
+
         // delete
         let res = test::TestRequest::delete().uri(&format!("/users/{}/notes/{}", uid, nid))
             .append_header(api_key.clone())
@@ -92,6 +196,47 @@ mod test_user_note_controller {
             .await;
         assert!(res.status().is_success());
 
+        // delete is delivered to the current follower and the former one which received the note
+        let jobs = delivery_job::Entity::find()
+            .filter(delivery_job::Column::UserId.eq(uid.clone()))
+            .all(&db).await.unwrap();
+        assert_eq!(jobs.len(), 5);
+        let delete: Value = serde_json::from_str(&jobs[3].body).unwrap();
+        assert_eq!(delete["type"], "Delete");
+        assert_eq!(delete["actor"], format!("http://test.example.com/users/{}", uid));
+        assert_eq!(delete["object"]["type"], "Tombstone");
+        assert_eq!(delete["object"]["id"], format!("http://test.example.com/notes/{}", nid));
+        assert_eq!(jobs[3].inbox, "https://remote.example.com/users/bar/inbox");
+        assert_eq!(jobs[4].body, jobs[3].body);
+        assert_eq!(jobs[4].inbox, "https://remote.example.com/users/foo/inbox");
+        assert!(note_recipient::Entity::find().all(&db).await.unwrap().iter().all(|r| r.note_id != nid));
+
+        // public url answers with a tombstone
+        let res = test::TestRequest::get().uri(&format!("/notes/{}", nid))
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 410);
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["type"], "Tombstone");
+        assert_eq!(body["formerType"], "Note");
+        assert_eq!(body["id"], format!("http://test.example.com/notes/{}", nid));
+
+        // edit deleted note (fail)
+        let res = test::TestRequest::put().uri(&format!("/users/{}/notes/{}", uid, nid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "foobarbaz444"}"#)
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // delete again (fail)
+        let res = test::TestRequest::delete().uri(&format!("/users/{}/notes/{}", uid, nid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
         // list
         let res = test::TestRequest::get().uri(&format!("/users/{}/notes", uid))
             .append_header(api_key.clone())
@@ -107,5 +252,153 @@ mod test_user_note_controller {
             .send_request(&app)
             .await;
         assert!(!res.status().is_success());
+
+        // list received notes
+        let res = test::TestRequest::get().uri(&format!("/users/{}/received_notes", uid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: ReceivedNoteListResponse = test::read_body_json(res).await;
+        assert_eq!(body.total, 0);
+        assert_eq!(body.notes.len(), 0);
+
+        // list received notes of unknown user (fail)
+        let res = test::TestRequest::get().uri("/users/unknown/received_notes")
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // list received notes without admin api-key (fail)
+        let res = test::TestRequest::get().uri(&format!("/users/{}/received_notes", uid))
+            .send_request(&app)
+            .await;
+        assert!(!res.status().is_success());
+
+        // add unlisted note
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes", uid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "foobarbaz444", "visibility": "unlisted"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserNoteResponse = test::read_body_json(res).await;
+        assert!(!body.pinned);
+        let pid = body.id.clone();
+
+        // add followers only note
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes", uid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "foobarbaz555", "visibility": "followers"}"#)
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserNoteResponse = test::read_body_json(res).await;
+        assert_eq!(body.visibility, "followers");
+        let fid = body.id.clone();
+
+        // followers only note is addressed to followers
+        let jobs = delivery_job::Entity::find()
+            .filter(delivery_job::Column::UserId.eq(uid.clone()))
+            .all(&db).await.unwrap();
+        let create: Value = serde_json::from_str(&jobs[jobs.len() - 1].body).unwrap();
+        assert_eq!(create["to"][0], format!("http://test.example.com/users/{}/followers", uid));
+        assert!(create["cc"].as_array().map_or(true, |c| c.is_empty()));
+
+        // followers only note without signature (fail)
+        let res = test::TestRequest::get().uri(&format!("/notes/{}", fid))
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 404);
+
+        // add with unknown visibility (fail)
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes", uid))
+            .append_header(api_key.clone())
+            .append_header(("Content-Type", "application/json"))
+            .set_payload(r#"{"content": "foobarbaz666", "visibility": "direct"}"#)
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 400);
+
+        // pin
+        let featured = format!("http://test.example.com/users/{}/collections/featured", uid);
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes/{}/pin", uid, pid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserNoteResponse = test::read_body_json(res).await;
+        assert!(body.pinned);
+
+        // add is delivered to the follower
+        let jobs = delivery_job::Entity::find()
+            .filter(delivery_job::Column::UserId.eq(uid.clone()))
+            .all(&db).await.unwrap();
+        let num_jobs = jobs.len();
+        let add: Value = serde_json::from_str(&jobs[num_jobs - 1].body).unwrap();
+        assert_eq!(add["type"], "Add");
+        assert_eq!(add["actor"], format!("http://test.example.com/users/{}", uid));
+        assert_eq!(add["object"], format!("http://test.example.com/notes/{}", pid));
+        assert_eq!(add["target"], featured);
+
+        // pin again does nothing
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes/{}/pin", uid, pid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let jobs = delivery_job::Entity::find()
+            .filter(delivery_job::Column::UserId.eq(uid.clone()))
+            .all(&db).await.unwrap();
+        assert_eq!(jobs.len(), num_jobs);
+
+        // pin followers only note (fail)
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes/{}/pin", uid, fid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert_eq!(res.status().as_u16(), 400);
+
+        // featured collection
+        let res = test::TestRequest::get().uri(&format!("/users/{}/collections/featured", uid))
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["type"], "OrderedCollection");
+        assert_eq!(body["id"], featured);
+        assert_eq!(body["totalItems"], 1);
+        assert_eq!(body["orderedItems"][0]["type"], "Note");
+        assert_eq!(body["orderedItems"][0]["id"], format!("http://test.example.com/notes/{}", pid));
+
+        // unpin
+        let res = test::TestRequest::post().uri(&format!("/users/{}/notes/{}/unpin", uid, pid))
+            .append_header(api_key.clone())
+            .send_request(&app)
+            .await;
+        assert!(res.status().is_success());
+        let body: UserNoteResponse = test::read_body_json(res).await;
+        assert!(!body.pinned);
+
+        // remove is delivered to the follower
+        let jobs = delivery_job::Entity::find()
+            .filter(delivery_job::Column::UserId.eq(uid.clone()))
+            .all(&db).await.unwrap();
+        assert_eq!(jobs.len(), num_jobs + 1);
+        let remove: Value = serde_json::from_str(&jobs[num_jobs].body).unwrap();
+        assert_eq!(remove["type"], "Remove");
+        assert_eq!(remove["object"], format!("http://test.example.com/notes/{}", pid));
+        assert_eq!(remove["target"], featured);
+
+        // featured collection is empty
+        let res = test::TestRequest::get().uri(&format!("/users/{}/collections/featured", uid))
+            .send_request(&app)
+            .await;
+        let body: Value = test::read_body_json(res).await;
+        assert_eq!(body["totalItems"], 0);
+        assert_eq!(body["orderedItems"].as_array().unwrap().len(), 0);
     }
 }